@@ -0,0 +1,226 @@
+use anyhow::Result;
+use colored::Colorize;
+use serde::Serialize;
+use std::path::Path;
+
+use lango::dict::DictionaryService;
+use lango::tr;
+use lango::types::EXAM_TAGS;
+
+use crate::known::KnownWords;
+use crate::report::{display_width, item, percent, ratio, section};
+use crate::text::{self, Lemma, Vocabulary};
+
+/// 词频段上限（含），超出最后一段的计入 `> 20000`
+const FREQUENCY_BANDS: &[i64] = &[1000, 3000, 5000, 10000, 20000];
+
+/// 无词频排名一栏的标签（JSON 中保持不变，终端输出时翻译）
+const UNRANKED: &str = "unranked";
+
+/// 文本词汇覆盖报告
+#[derive(Serialize)]
+struct Report {
+    /// 单词总数
+    tokens: usize,
+    /// 能在词库中查到的单词数
+    found_tokens: usize,
+    /// 不同词目（原形）数
+    lemmas: usize,
+    /// 各考试标签的词目数与单词覆盖率
+    tags: Vec<Share>,
+    /// 各词频段的词目数与单词覆盖率
+    frequency: Vec<Share>,
+    /// 牛津 3000 核心词汇的词目数与单词覆盖率
+    oxford: Share,
+    /// 熟词表的单词覆盖率（熟词表为空时省略）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    known: Option<Share>,
+    /// 最难的词（词频排名最靠后）
+    hardest: Vec<Hard>,
+    /// 词库未收录的词
+    unknown: Vec<String>,
+}
+
+/// 满足某一条件的词目数，及其出现次数占可查单词数的比例
+#[derive(Serialize)]
+struct Share {
+    label: String,
+    lemmas: usize,
+    coverage: f64,
+}
+
+#[derive(Serialize)]
+struct Hard {
+    word: String,
+    rank: Option<i64>,
+    tag: Option<String>,
+    translation: String,
+}
+
+/// 分析文本的词汇难度与覆盖率
+pub fn run(service: &DictionaryService, path: &Path, top: usize, json: bool) -> Result<()> {
+    let input = text::read_input(path)?;
+    let vocabulary = Vocabulary::collect(service, &input)?;
+    let known = KnownWords::load()?;
+    let report = build_report(&vocabulary, &known, top);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print_report(&report);
+    }
+    Ok(())
+}
+
+fn build_report(vocabulary: &Vocabulary, known: &KnownWords, top: usize) -> Report {
+    let found_tokens: usize = vocabulary.lemmas.iter().map(|l| l.count).sum();
+    let share = |label: String, matches: &dyn Fn(&Lemma) -> bool| {
+        let matched: Vec<&Lemma> = vocabulary.lemmas.iter().filter(|l| matches(l)).collect();
+        let count: usize = matched.iter().map(|l| l.count).sum();
+        Share {
+            label,
+            lemmas: matched.len(),
+            coverage: ratio(count, found_tokens),
+        }
+    };
+
+    let tags = EXAM_TAGS
+        .iter()
+        .map(|tag| share(tag.to_string(), &|l| l.info.tags().any(|t| t == *tag)))
+        .collect();
+
+    let mut frequency = Vec::new();
+    let mut lower = 0;
+    for &upper in FREQUENCY_BANDS {
+        frequency.push(share(format!("{}-{}", lower + 1, upper), &|l| {
+            l.info.rank().is_some_and(|r| r > lower && r <= upper)
+        }));
+        lower = upper;
+    }
+    frequency.push(share(format!("> {}", lower), &|l| {
+        l.info.rank().is_some_and(|r| r > lower)
+    }));
+    frequency.push(share(UNRANKED.to_string(), &|l| l.info.rank().is_none()));
+
+    let oxford = share("oxford".to_string(), &|l| l.info.oxford);
+    let known =
+        (!known.is_empty()).then(|| share("known".to_string(), &|l| known.contains(&l.info.word)));
+
+    // 无词频的词通常最生僻，排在最前
+    let mut ranked: Vec<&Lemma> = vocabulary.lemmas.iter().collect();
+    ranked.sort_by_key(|l| std::cmp::Reverse(l.info.rank().unwrap_or(i64::MAX)));
+    let hardest = ranked
+        .into_iter()
+        .take(top)
+        .map(|l| Hard {
+            word: l.info.word.clone(),
+            rank: l.info.rank(),
+            tag: l.info.tag.clone().filter(|t| !t.is_empty()),
+            translation: text::brief_translation(&l.info),
+        })
+        .collect();
+
+    Report {
+        tokens: vocabulary.tokens,
+        found_tokens,
+        lemmas: vocabulary.lemmas.len(),
+        tags,
+        frequency,
+        oxford,
+        known,
+        hardest,
+        unknown: vocabulary.unknown.clone(),
+    }
+}
+
+fn print_report(report: &Report) {
+    println!();
+    section(tr!("analyze.overview"));
+    item(tr!("analyze.tokens"), &report.tokens.to_string());
+    item(
+        tr!("analyze.found_tokens"),
+        &format!(
+            "{} ({})",
+            report.found_tokens,
+            percent(ratio(report.found_tokens, report.tokens))
+        ),
+    );
+    item(tr!("analyze.lemmas"), &report.lemmas.to_string());
+    item(
+        tr!("analyze.oxford"),
+        &tr!(
+            "analyze.share",
+            percent(report.oxford.coverage),
+            report.oxford.lemmas
+        ),
+    );
+    if let Some(ref known) = report.known {
+        item(
+            tr!("analyze.known"),
+            &tr!("analyze.share", percent(known.coverage), known.lemmas),
+        );
+    }
+    println!();
+
+    section(tr!("analyze.tags"));
+    print_shares(&report.tags);
+    println!();
+
+    section(tr!("analyze.frequency"));
+    print_shares(&report.frequency);
+    println!();
+
+    if !report.hardest.is_empty() {
+        section(tr!("analyze.hardest"));
+        let width = report
+            .hardest
+            .iter()
+            .map(|h| h.word.chars().count())
+            .max()
+            .unwrap_or(0);
+        for hard in &report.hardest {
+            let padding = " ".repeat(width - hard.word.chars().count());
+            let rank = hard
+                .rank
+                .map(|r| format!("#{}", r))
+                .unwrap_or_else(|| "-".to_string());
+            println!(
+                "    {}{}  {:>7}  {}",
+                hard.word.bright_blue().bold(),
+                padding,
+                rank.dimmed(),
+                hard.translation.green()
+            );
+        }
+        println!();
+    }
+
+    if !report.unknown.is_empty() {
+        println!(
+            "  {}",
+            tr!("gloss.unknown", report.unknown.join(", ")).dimmed()
+        );
+        println!();
+    }
+}
+
+/// 以条形图输出各项的单词覆盖率与词目数
+fn print_shares(shares: &[Share]) {
+    const BAR_WIDTH: usize = 20;
+    for share in shares {
+        let label = match share.label.as_str() {
+            UNRANKED => tr!("analyze.no_rank"),
+            label => label,
+        };
+        let filled = (share.coverage * BAR_WIDTH as f64).round() as usize;
+        println!(
+            "    {}{} {}{} {:>6}  {}",
+            label,
+            " ".repeat(12usize.saturating_sub(display_width(label))),
+            "█".repeat(filled).cyan(),
+            "░".repeat(BAR_WIDTH - filled).dimmed(),
+            percent(share.coverage),
+            tr!("analyze.lemma_count", share.lemmas).dimmed()
+        );
+    }
+}
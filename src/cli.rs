@@ -1,12 +1,16 @@
-use clap::{Parser, Subcommand};
+use clap::builder::PossibleValuesParser;
+use clap::{Args, Command, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
 use std::path::PathBuf;
 
+use lango::i18n::{self, Lang};
+use lango::types::{EXAM_TAGS, WordFilter};
+
 #[derive(Parser, Debug)]
 #[command(
     name = "lango",
     about = "快速英语词典查询工具",
     version,
-    after_help = "示例:\n  lango hello              查询单词\n  lango \"machine learning\"  查询词组\n  lango -e hello            显示英文释义\n  lango -x hello            显示例句\n  lango --online hello      强制在线查询(默认显示英文释义)"
+    after_help = "示例:\n  lango hello              查询单词\n  lango \"machine learning\"  查询词组\n  lango -e hello            显示英文释义\n  lango -x hello            显示例句\n  lango -p hello            播放发音\n  lango --online hello      强制在线查询(默认显示英文释义)\n  lango --all hello         对比所有词典的结果"
 )]
 pub struct Cli {
     #[command(subcommand)]
@@ -24,13 +28,60 @@ pub struct Cli {
     #[arg(short = 'x', long = "examples", global = true)]
     pub show_examples: bool,
 
+    /// 播放单词发音（在线真人发音，无网络时用 espeak 朗读）
+    #[arg(short = 'p', long = "pronounce")]
+    pub pronounce: bool,
+
     /// 强制使用在线词典
     #[arg(long = "online", global = true)]
     pub force_online: bool,
 
+    /// 查询所有词典（本地与在线）并分别显示结果
+    #[arg(
+        short = 'a',
+        long = "all",
+        conflicts_with = "force_online",
+        global = true
+    )]
+    pub all_sources: bool,
+
+    /// 指定查询的词库（可多次指定或用逗号分隔，默认查询所有已启用词库）
+    #[arg(
+        short = 'd',
+        long = "dict",
+        value_name = "NAME",
+        value_delimiter = ',',
+        global = true
+    )]
+    pub dicts: Vec<String>,
+
+    /// 本次运行的在线请求次数上限（含重试）
+    #[arg(long = "online-budget", value_name = "N", global = true)]
+    pub online_budget: Option<usize>,
+
+    /// 未找到时自动显示最接近的拼写建议（也可在配置文件中设置 auto_correct = true）
+    #[arg(
+        long = "auto-correct",
+        overrides_with = "no_auto_correct",
+        global = true
+    )]
+    pub auto_correct: bool,
+
+    /// 关闭自动纠正（覆盖配置文件）
+    #[arg(long = "no-auto-correct", global = true)]
+    pub no_auto_correct: bool,
+
+    /// 未找到时不进入交互式建议选择，直接列出建议
+    #[arg(long = "no-interactive", global = true)]
+    pub no_interactive: bool,
+
     /// 例句数量上限
     #[arg(short = 'n', long = "num-examples", default_value = "3", global = true)]
     pub max_examples: usize,
+
+    /// 界面语言（默认根据配置文件或 LANG 环境变量选择）
+    #[arg(long = "lang", value_name = "LANG", global = true)]
+    pub lang: Option<Lang>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -38,7 +89,724 @@ pub enum Commands {
     /// 初始化/重新下载词库
     Setup {
         /// 从本地文件导入词库
-        #[arg(long = "import")]
+        #[arg(long = "import", conflicts_with = "check_update")]
         import_path: Option<PathBuf>,
+
+        /// 导入为指定名称的附加词库（默认导入为 ecdict）
+        #[arg(long = "name", requires = "import_path")]
+        name: Option<String>,
+
+        /// 检查是否有新版本词库
+        #[arg(long = "check-update")]
+        check_update: bool,
+    },
+    /// 升级词库到最新版本（仅在有新版本时下载）
+    Update {
+        /// 已安装词库版本未知（如手动导入）时仍下载最新版本并覆盖
+        #[arg(long = "force")]
+        force: bool,
+    },
+    /// 搜索词组/习语，`*` 匹配任意个单词
+    #[command(
+        after_help = "示例:\n  lango phrase take off                 包含 take off 的词组\n  lango phrase take '*' into account    take ... into account\n  lango phrase -u large by and          不限词序\n  lango phrase take                     所有包含 take 的词组"
+    )]
+    Phrase {
+        /// 词组模式（空格分隔的单词，`*` 为通配）
+        #[arg(value_name = "PATTERN", required = true, num_args = 1..)]
+        pattern: Vec<String>,
+
+        /// 不限制单词顺序
+        #[arg(short = 'u', long = "any-order")]
+        any_order: bool,
+
+        /// 最多显示的结果数
+        #[arg(short = 'l', long = "limit", default_value = "20")]
+        limit: usize,
+    },
+    /// 按通配符或正则匹配词头（填字游戏等）
+    #[command(
+        name = "match",
+        after_help = "示例:\n  lango match 'c?t'                 c 开头、t 结尾的三字母单词\n  lango match '*tion' --len 8       以 tion 结尾的 8 字母单词\n  lango match -r '^un.*able$'       正则匹配\n  lango match '*ing' --page 2       查看第二页"
+    )]
+    Match {
+        /// 通配模式：`?` 匹配单个字符，`*` 匹配任意个字符
+        #[arg(value_name = "PATTERN", required_unless_present = "regex")]
+        pattern: Option<String>,
+
+        /// 使用正则表达式匹配（不区分大小写）
+        #[arg(
+            short = 'r',
+            long = "regex",
+            value_name = "REGEX",
+            conflicts_with = "pattern"
+        )]
+        regex: Option<String>,
+
+        /// 限定单词长度
+        #[arg(long = "len", value_name = "N")]
+        len: Option<usize>,
+
+        /// 页码（从 1 开始）
+        #[arg(long = "page", default_value = "1", value_parser = clap::value_parser!(u64).range(1..))]
+        page: u64,
+
+        /// 每页结果数
+        #[arg(
+            short = 'l',
+            long = "limit",
+            default_value = "20",
+            value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+        )]
+        limit: usize,
+    },
+    /// 管理已安装的词库
+    Dicts {
+        #[command(subcommand)]
+        action: DictsAction,
+    },
+    /// 生成 shell 补全脚本
+    #[command(
+        after_help = "示例:\n  source <(lango completions bash)\n  source <(lango completions zsh)\n  lango completions fish > ~/.config/fish/completions/lango.fish"
+    )]
+    Completions {
+        /// 目标 shell
+        shell: clap_complete::Shell,
     },
+    /// 生成 man 手册页或 Markdown 命令参考
+    #[command(
+        after_help = "示例:\n  lango docs > lango.1                            输出主命令的手册页\n  lango docs --out /usr/share/man/man1            为所有子命令生成手册页\n  lango docs --format markdown > docs/cli.md      输出 Markdown 参考"
+    )]
+    Docs {
+        /// 文档格式：man 为 roff 手册页，markdown 为 Markdown 命令参考
+        #[arg(short = 'f', long = "format", value_enum, default_value = "man")]
+        format: DocFormat,
+
+        /// 输出目录（未指定时输出到标准输出）
+        #[arg(short = 'o', long = "out", value_name = "DIR")]
+        out_dir: Option<PathBuf>,
+    },
+    /// 启动本地 HTTP 服务，以 JSON 提供查询接口
+    #[command(
+        after_help = "示例:\n  lango serve                          监听 127.0.0.1:8080\n  lango serve --port 9000 -d ecdict    指定端口与词库\n  curl 'http://127.0.0.1:8080/lookup?q=hello'\n  curl 'http://127.0.0.1:8080/suggest?q=recieve'\n  curl 'http://127.0.0.1:8080/search?q=*tion&len=8'"
+    )]
+    Serve {
+        /// 监听端口
+        #[arg(short = 'p', long = "port", default_value = "8080")]
+        port: u16,
+
+        /// 监听地址（默认仅本机可访问）
+        #[arg(long = "host", default_value = "127.0.0.1")]
+        host: String,
+
+        /// 允许跨域访问的来源（如 `chrome-extension://<id>`，`*` 表示任意网页），默认不允许
+        #[arg(long = "cors", value_name = "ORIGIN")]
+        cors: Option<String>,
+    },
+    /// 为英文文本生成生词表（词形还原后按难度筛选）
+    #[command(
+        after_help = "示例:\n  lango gloss article.txt                      列出文中所有单词\n  lango gloss article.txt --level cet6         只列出 CET6 及以上的词\n  lango gloss article.txt --min-rank 5000      只列出词频排名 5000 之后的词\n  lango gloss article.txt -k known.txt -f markdown > glossary.md\n  pbpaste | lango gloss -"
+    )]
+    Gloss {
+        /// 文本文件（`-` 表示标准输入）
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
+
+        /// 熟词表文件（每行一个单词），其中的单词不列出
+        #[arg(short = 'k', long = "known", value_name = "FILE")]
+        known: Option<PathBuf>,
+
+        /// 只列出词频排名在 N 之后的单词
+        #[arg(long = "min-rank", value_name = "N")]
+        min_rank: Option<i64>,
+
+        /// 只列出不属于更低级别考试的单词（如 cet6 表示 CET6 及以上）
+        #[arg(short = 'l', long = "level", value_name = "TAG", value_parser = PossibleValuesParser::new(EXAM_TAGS))]
+        level: Option<String>,
+
+        /// 不排除熟词表（`lango known`）中的单词
+        #[arg(long = "no-known")]
+        no_known: bool,
+
+        /// 输出格式
+        #[arg(short = 'f', long = "format", value_enum, default_value = "text")]
+        format: GlossFormat,
+    },
+    /// 分析文本的词汇难度：考试标签分布、词频段、牛津 3000 覆盖率与最难的词
+    #[command(
+        after_help = "示例:\n  lango analyze article.txt              输出覆盖报告\n  lango analyze article.txt --top 20     列出最难的 20 个词\n  lango analyze article.txt --json       输出 JSON（便于批量评级）"
+    )]
+    Analyze {
+        /// 文本文件（`-` 表示标准输入）
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
+
+        /// 列出的最难单词数
+        #[arg(short = 't', long = "top", default_value = "10")]
+        top: usize,
+
+        /// 以 JSON 格式输出
+        #[arg(long = "json")]
+        json: bool,
+    },
+    /// 管理熟词表（已掌握的单词不出现在生词表中，查询时未掌握的词标为生词）
+    #[command(
+        after_help = "示例:\n  lango known import --tag zk,gk        导入中考、高考词汇\n  lango known import words.txt           从文件导入（每行一个单词）\n  lango known add serendipity            标记为已掌握\n  lango known review --tag cet4          逐个复习 CET4 词汇并标记"
+    )]
+    Known {
+        #[command(subcommand)]
+        action: KnownAction,
+    },
+    /// 监视剪贴板，复制单词或短语时自动查询
+    #[command(
+        group(clap::ArgGroup::new("source").required(true).args(["clipboard", "primary"])),
+        after_help = "示例:\n  lango watch --clipboard              复制单词即显示释义\n  lango watch --primary                选中即查询（Linux 主选区）\n  lango watch --clipboard -e           同时显示英文释义"
+    )]
+    Watch {
+        /// 监视系统剪贴板
+        #[arg(long = "clipboard")]
+        clipboard: bool,
+
+        /// 监视主选区（Linux 下鼠标选中的文本，无需复制）
+        #[arg(long = "primary")]
+        primary: bool,
+
+        /// 轮询间隔（毫秒）
+        #[arg(long = "interval", value_name = "MS", default_value = "300")]
+        interval: u64,
+    },
+    /// 随机抽取一个单词并显示词条
+    #[command(
+        after_help = "示例:\n  lango random                     从常见词中随机抽取\n  lango random --tag cet6          CET6 词汇\n  lango random --oxford -e         牛津 3000 核心词汇，显示英文释义\n  lango random --max-rank 5000     词频排名前 5000 的词"
+    )]
+    Random {
+        #[command(flatten)]
+        pool: PoolArgs,
+    },
+    /// 每日一词：同一天总是显示同一个单词（适合放在 shell 启动脚本中）
+    #[command(
+        after_help = "示例:\n  lango daily                      今天的单词\n  lango daily --tag gre            从 GRE 词汇中选取\n  echo 'lango daily --tag cet6' >> ~/.bashrc"
+    )]
+    Daily {
+        #[command(flatten)]
+        pool: PoolArgs,
+    },
+    /// 单词测验：看释义选词、拼写或看词选释义，结果记录供统计与错题复习
+    #[command(
+        after_help = "示例:\n  lango quiz --tag cet4                CET4 词汇选择题（20 题）\n  lango quiz --mode spell -c 10        看释义拼写 10 个单词\n  lango quiz --mode reverse --oxford   看单词选释义\n  lango quiz --mistakes                重做上次答错的单词\n  lango quiz --stats                   查看历史正确率与易错词"
+    )]
+    Quiz {
+        /// 题型：choice 看释义选单词，spell 看释义拼写，reverse 看单词选释义
+        #[arg(short = 'm', long = "mode", value_enum, default_value = "choice")]
+        mode: QuizMode,
+
+        /// 题目数量
+        #[arg(
+            short = 'c',
+            long = "count",
+            default_value = "20",
+            value_parser = clap::builder::RangedU64ValueParser::<usize>::new().range(1..)
+        )]
+        count: usize,
+
+        /// 只测验最近一次答错的单词
+        #[arg(long = "mistakes", conflicts_with_all = ["tag", "oxford", "collins", "max_rank"])]
+        mistakes: bool,
+
+        /// 显示历史测验统计，不进行测验
+        #[arg(long = "stats")]
+        stats: bool,
+
+        #[command(flatten)]
+        pool: PoolArgs,
+    },
+    /// 以语言服务器（LSP）方式运行，为编辑器提供悬停释义与拼写检查
+    #[command(
+        after_help = "示例（Neovim）:\n  vim.lsp.start({ name = 'lango', cmd = { 'lango', 'lsp', '--diagnostics' } })\n\n示例（Helix languages.toml）:\n  [language-server.lango]\n  command = \"lango\"\n  args = [\"lsp\", \"--diagnostics\"]"
+    )]
+    Lsp {
+        /// 将词库未收录的单词标记为拼写问题
+        #[arg(long = "diagnostics")]
+        diagnostics: bool,
+    },
+    /// 输出诊断信息（词库、配置、缓存、网络）
+    #[command(visible_alias = "info")]
+    Doctor {
+        /// 为缺少拼写索引的词库建立索引
+        #[arg(long = "fix")]
+        fix: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum DictsAction {
+    /// 列出已安装的词库
+    List,
+    /// 启用词库
+    Enable { name: String },
+    /// 禁用词库（仍可通过 --dict 显式查询）
+    Disable { name: String },
+    /// 删除词库
+    Remove { name: String },
+}
+
+/// 单词池筛选参数（各条件同时满足；均未指定时从带有考试标签、柯林斯星级或牛津标记的常见词中选取）
+#[derive(Args, Debug)]
+pub struct PoolArgs {
+    /// 只选取带有该考试标签的单词
+    #[arg(short = 't', long = "tag", value_name = "TAG", value_parser = PossibleValuesParser::new(EXAM_TAGS))]
+    pub tag: Option<String>,
+
+    /// 只选取牛津 3000 核心词汇
+    #[arg(long = "oxford")]
+    pub oxford: bool,
+
+    /// 柯林斯星级下限
+    #[arg(long = "collins", value_name = "STARS", value_parser = clap::value_parser!(u8).range(1..=5))]
+    pub collins: Option<u8>,
+
+    /// 只选取词频排名前 N 的单词
+    #[arg(long = "max-rank", value_name = "N")]
+    pub max_rank: Option<i64>,
+}
+
+impl PoolArgs {
+    pub fn filter(&self) -> WordFilter {
+        WordFilter {
+            tag: self.tag.clone(),
+            oxford: self.oxford,
+            min_collins: self.collins,
+            max_rank: self.max_rank,
+        }
+    }
+}
+
+#[derive(Subcommand, Debug)]
+pub enum KnownAction {
+    /// 标记单词为已掌握（按原形保存）
+    Add {
+        #[arg(required = true)]
+        words: Vec<String>,
+    },
+    /// 从熟词表中移除单词
+    Remove {
+        #[arg(required = true)]
+        words: Vec<String>,
+    },
+    /// 列出熟词表
+    List,
+    /// 从文件或考试标签导入熟词
+    Import {
+        /// 单词文件（每行一个单词，`#` 开头为注释）
+        #[arg(value_name = "FILE", required_unless_present = "tags")]
+        file: Option<PathBuf>,
+
+        /// 导入带有这些考试标签的全部单词（逗号分隔）
+        #[arg(
+            short = 't',
+            long = "tag",
+            value_name = "TAG",
+            value_delimiter = ',',
+            value_parser = PossibleValuesParser::new(EXAM_TAGS)
+        )]
+        tags: Vec<String>,
+    },
+    /// 逐个复习尚未掌握的单词（常用词在前），认识的标记为已掌握
+    Review {
+        /// 复习的考试词汇
+        #[arg(short = 't', long = "tag", value_name = "TAG", default_value = "cet4", value_parser = PossibleValuesParser::new(EXAM_TAGS))]
+        tag: String,
+
+        /// 本次复习的单词数
+        #[arg(short = 'c', long = "count", default_value = "20")]
+        count: usize,
+    },
+}
+
+/// 文档格式
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum DocFormat {
+    Man,
+    Markdown,
 }
+
+/// 生词表输出格式
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum GlossFormat {
+    Text,
+    Markdown,
+    Csv,
+}
+
+/// 测验题型
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuizMode {
+    Choice,
+    Spell,
+    Reverse,
+}
+
+/// 构建命令定义，界面语言为英文时替换帮助文本
+pub fn command() -> Command {
+    let cmd = Cli::command();
+    match i18n::current() {
+        Lang::ZhCn => cmd,
+        Lang::En => localize(cmd, "lango"),
+    }
+}
+
+/// 按当前界面语言解析命令行参数
+pub fn parse() -> Cli {
+    let matches = command().get_matches();
+    Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit())
+}
+
+/// 用 `HELP_EN` 替换命令及其子命令的说明、示例与参数帮助
+fn localize(mut cmd: Command, path: &str) -> Command {
+    if let Some(about) = help_en(&format!("{}.about", path)) {
+        cmd = cmd.about(about);
+    }
+    if let Some(examples) = help_en(&format!("{}.examples", path)) {
+        cmd = cmd.after_help(examples);
+    }
+
+    let ids: Vec<String> = cmd
+        .get_arguments()
+        .map(|a| a.get_id().to_string())
+        .collect();
+    for id in ids {
+        if let Some(help) = help_en(&format!("{}.{}", path, id)) {
+            cmd = cmd.mut_arg(id, |arg| arg.help(help));
+        }
+    }
+
+    let names: Vec<String> = cmd
+        .get_subcommands()
+        .map(|s| s.get_name().to_string())
+        .collect();
+    for name in names {
+        let sub_path = format!("{}.{}", path, name);
+        cmd = cmd.mut_subcommand(name, |sub| localize(sub, &sub_path));
+    }
+    cmd
+}
+
+fn help_en(key: &str) -> Option<&'static str> {
+    HELP_EN.iter().find(|(k, _)| *k == key).map(|(_, v)| *v)
+}
+
+/// 英文帮助文本，键为 `命令路径.参数名`（中文即上方的文档注释）
+const HELP_EN: &[(&str, &str)] = &[
+    ("lango.about", "Fast English dictionary lookup tool"),
+    (
+        "lango.examples",
+        "Examples:\n  lango hello               look up a word\n  lango \"machine learning\"  look up a phrase\n  lango -e hello            show English definitions\n  lango -x hello            show example sentences\n  lango -p hello            play the pronunciation\n  lango --online hello      force online lookup (shows English definitions)\n  lango --all hello         compare results from every dictionary",
+    ),
+    ("lango.query", "Word or phrase to look up"),
+    ("lango.show_english", "Show English definitions"),
+    ("lango.show_examples", "Show example sentences"),
+    (
+        "lango.pronounce",
+        "Play the pronunciation (recorded audio online, espeak when offline)",
+    ),
+    ("lango.force_online", "Force the online dictionary"),
+    (
+        "lango.all_sources",
+        "Query every dictionary (local and online) and show each result separately",
+    ),
+    (
+        "lango.dicts",
+        "Dictionaries to query (repeat or separate with commas; defaults to all enabled dictionaries)",
+    ),
+    (
+        "lango.online_budget",
+        "Maximum number of online requests for this run (including retries)",
+    ),
+    (
+        "lango.auto_correct",
+        "Show the closest spelling suggestion when nothing is found (or set auto_correct = true in the config file)",
+    ),
+    (
+        "lango.no_auto_correct",
+        "Disable auto-correct (overrides the config file)",
+    ),
+    (
+        "lango.no_interactive",
+        "List suggestions instead of picking one interactively",
+    ),
+    ("lango.max_examples", "Maximum number of example sentences"),
+    (
+        "lango.lang",
+        "Interface language (defaults to the config file or the LANG environment variable)",
+    ),
+    (
+        "lango.setup.about",
+        "Initialize or re-download the dictionary",
+    ),
+    (
+        "lango.setup.import_path",
+        "Import a dictionary from a local file",
+    ),
+    (
+        "lango.setup.name",
+        "Import as an additional dictionary with this name (defaults to ecdict)",
+    ),
+    (
+        "lango.setup.check_update",
+        "Check for a newer dictionary release",
+    ),
+    (
+        "lango.update.about",
+        "Upgrade the dictionary to the latest release (downloads only when newer)",
+    ),
+    (
+        "lango.update.force",
+        "Download and install the latest release even when the installed version is unknown (e.g. imported manually)",
+    ),
+    (
+        "lango.phrase.about",
+        "Search phrases and idioms, `*` matches any number of words",
+    ),
+    (
+        "lango.phrase.examples",
+        "Examples:\n  lango phrase take off                 phrases containing take off\n  lango phrase take '*' into account    take ... into account\n  lango phrase -u large by and          any word order\n  lango phrase take                     every phrase containing take",
+    ),
+    (
+        "lango.phrase.pattern",
+        "Phrase pattern (space-separated words, `*` is a wildcard)",
+    ),
+    ("lango.phrase.any_order", "Ignore word order"),
+    ("lango.phrase.limit", "Maximum number of results"),
+    (
+        "lango.match.about",
+        "Match headwords by wildcard or regex (crosswords and the like)",
+    ),
+    (
+        "lango.match.examples",
+        "Examples:\n  lango match 'c?t'                 three-letter words starting with c and ending with t\n  lango match '*tion' --len 8       8-letter words ending in tion\n  lango match -r '^un.*able$'       regex match\n  lango match '*ing' --page 2       show the second page",
+    ),
+    (
+        "lango.match.pattern",
+        "Wildcard pattern: `?` matches one character, `*` matches any number",
+    ),
+    (
+        "lango.match.regex",
+        "Match with a regular expression (case-insensitive)",
+    ),
+    ("lango.match.len", "Only words of this length"),
+    ("lango.match.page", "Page number (starting at 1)"),
+    ("lango.match.limit", "Results per page"),
+    ("lango.dicts.about", "Manage installed dictionaries"),
+    ("lango.dicts.list.about", "List installed dictionaries"),
+    ("lango.dicts.enable.about", "Enable a dictionary"),
+    (
+        "lango.dicts.disable.about",
+        "Disable a dictionary (it can still be queried with --dict)",
+    ),
+    ("lango.dicts.remove.about", "Remove a dictionary"),
+    (
+        "lango.completions.about",
+        "Generate shell completion scripts",
+    ),
+    (
+        "lango.completions.examples",
+        "Examples:\n  source <(lango completions bash)\n  source <(lango completions zsh)\n  lango completions fish > ~/.config/fish/completions/lango.fish",
+    ),
+    ("lango.completions.shell", "Target shell"),
+    (
+        "lango.docs.about",
+        "Generate man pages or a Markdown command reference",
+    ),
+    (
+        "lango.docs.examples",
+        "Examples:\n  lango docs > lango.1                            man page for the main command\n  lango docs --out /usr/share/man/man1            man pages for every subcommand\n  lango docs --format markdown > docs/cli.md      Markdown reference",
+    ),
+    (
+        "lango.docs.format",
+        "Output format: man for roff man pages, markdown for a Markdown reference",
+    ),
+    (
+        "lango.docs.out_dir",
+        "Output directory (prints to stdout when omitted)",
+    ),
+    (
+        "lango.serve.about",
+        "Start a local HTTP server that answers lookups as JSON",
+    ),
+    (
+        "lango.serve.examples",
+        "Examples:\n  lango serve                          listen on 127.0.0.1:8080\n  lango serve --port 9000 -d ecdict    choose the port and dictionary\n  curl 'http://127.0.0.1:8080/lookup?q=hello'\n  curl 'http://127.0.0.1:8080/suggest?q=recieve'\n  curl 'http://127.0.0.1:8080/search?q=*tion&len=8'",
+    ),
+    ("lango.serve.port", "Port to listen on"),
+    (
+        "lango.serve.host",
+        "Address to listen on (local access only by default)",
+    ),
+    (
+        "lango.serve.cors",
+        "Origin allowed to call the server from a browser (e.g. `chrome-extension://<id>`, `*` for any page); none by default",
+    ),
+    (
+        "lango.gloss.about",
+        "Build a glossary for an English text (lemmatized and filtered by difficulty)",
+    ),
+    (
+        "lango.gloss.examples",
+        "Examples:\n  lango gloss article.txt                      list every word in the text\n  lango gloss article.txt --level cet6         only CET6 and harder words\n  lango gloss article.txt --min-rank 5000      only words ranked beyond 5000\n  lango gloss article.txt -k known.txt -f markdown > glossary.md\n  pbpaste | lango gloss -",
+    ),
+    ("lango.gloss.file", "Text file (`-` reads standard input)"),
+    (
+        "lango.gloss.known",
+        "Known-words file (one word per line); these words are left out",
+    ),
+    (
+        "lango.gloss.min_rank",
+        "Only list words ranked beyond N by frequency",
+    ),
+    (
+        "lango.gloss.level",
+        "Only list words not in an easier exam (cet6 means CET6 and above)",
+    ),
+    (
+        "lango.gloss.no_known",
+        "Do not leave out words from the known-words list (`lango known`)",
+    ),
+    ("lango.gloss.format", "Output format"),
+    (
+        "lango.analyze.about",
+        "Grade a text's vocabulary: exam tag distribution, frequency bands, Oxford 3000 coverage and the hardest words",
+    ),
+    (
+        "lango.analyze.examples",
+        "Examples:\n  lango analyze article.txt              print the coverage report\n  lango analyze article.txt --top 20     list the 20 hardest words\n  lango analyze article.txt --json       print JSON (for batch grading)",
+    ),
+    ("lango.analyze.file", "Text file (`-` reads standard input)"),
+    ("lango.analyze.top", "Number of hardest words to list"),
+    ("lango.analyze.json", "Print the report as JSON"),
+    (
+        "lango.known.about",
+        "Manage the known-words list (known words are left out of glossaries; unknown ones are marked as new in lookups)",
+    ),
+    (
+        "lango.known.examples",
+        "Examples:\n  lango known import --tag zk,gk        import middle- and high-school vocabulary\n  lango known import words.txt           import from a file (one word per line)\n  lango known add serendipity            mark a word as known\n  lango known review --tag cet4          review CET4 words one by one",
+    ),
+    (
+        "lango.known.add.about",
+        "Mark words as known (stored as their base form)",
+    ),
+    (
+        "lango.known.remove.about",
+        "Remove words from the known-words list",
+    ),
+    ("lango.known.list.about", "List known words"),
+    (
+        "lango.known.import.about",
+        "Import known words from a file or exam tags",
+    ),
+    (
+        "lango.known.import.file",
+        "Word file (one word per line, `#` starts a comment)",
+    ),
+    (
+        "lango.known.import.tags",
+        "Import every word carrying these exam tags (comma separated)",
+    ),
+    (
+        "lango.known.review.about",
+        "Review words you have not marked yet (frequent first) and mark the ones you know",
+    ),
+    ("lango.known.review.tag", "Exam vocabulary to review"),
+    ("lango.known.review.count", "Number of words to review"),
+    (
+        "lango.watch.about",
+        "Watch the clipboard and look up words or short phrases as they are copied",
+    ),
+    (
+        "lango.watch.examples",
+        "Examples:\n  lango watch --clipboard              look up each copied word\n  lango watch --primary                look up selected text (Linux primary selection)\n  lango watch --clipboard -e           also show English definitions",
+    ),
+    ("lango.watch.clipboard", "Watch the system clipboard"),
+    (
+        "lango.watch.primary",
+        "Watch the primary selection (text selected with the mouse on Linux, no copy needed)",
+    ),
+    ("lango.watch.interval", "Polling interval in milliseconds"),
+    (
+        "lango.random.about",
+        "Pick a random word and show its entry",
+    ),
+    (
+        "lango.random.examples",
+        "Examples:\n  lango random                     pick from common words\n  lango random --tag cet6          CET6 vocabulary\n  lango random --oxford -e         Oxford 3000 words with English definitions\n  lango random --max-rank 5000     words ranked in the top 5000",
+    ),
+    (
+        "lango.daily.about",
+        "Word of the day: the same word all day long (handy in a shell startup file)",
+    ),
+    (
+        "lango.daily.examples",
+        "Examples:\n  lango daily                      today's word\n  lango daily --tag gre            pick from GRE vocabulary\n  echo 'lango daily --tag cet6' >> ~/.bashrc",
+    ),
+    ("lango.random.tag", "Only pick words carrying this exam tag"),
+    ("lango.random.oxford", "Only pick Oxford 3000 words"),
+    ("lango.random.collins", "Minimum Collins star rating"),
+    (
+        "lango.random.max_rank",
+        "Only pick words ranked in the top N by frequency",
+    ),
+    ("lango.daily.tag", "Only pick words carrying this exam tag"),
+    ("lango.daily.oxford", "Only pick Oxford 3000 words"),
+    ("lango.daily.collins", "Minimum Collins star rating"),
+    (
+        "lango.daily.max_rank",
+        "Only pick words ranked in the top N by frequency",
+    ),
+    (
+        "lango.quiz.about",
+        "Quiz yourself: pick the word for a definition, spell it, or pick the definition for a word",
+    ),
+    (
+        "lango.quiz.examples",
+        "Examples:\n  lango quiz --tag cet4                20 multiple-choice questions on CET4 words\n  lango quiz --mode spell -c 10        spell 10 words from their definitions\n  lango quiz --mode reverse --oxford   pick the definition for each word\n  lango quiz --mistakes                retry the words you got wrong\n  lango quiz --stats                   show accuracy and most-missed words",
+    ),
+    (
+        "lango.quiz.mode",
+        "Question type: choice picks the word for a definition, spell asks you to type it, reverse picks the definition for a word",
+    ),
+    ("lango.quiz.count", "Number of questions"),
+    (
+        "lango.quiz.mistakes",
+        "Only quiz words whose latest answer was wrong",
+    ),
+    (
+        "lango.quiz.stats",
+        "Show statistics from past quizzes instead of starting one",
+    ),
+    ("lango.quiz.tag", "Only pick words carrying this exam tag"),
+    ("lango.quiz.oxford", "Only pick Oxford 3000 words"),
+    ("lango.quiz.collins", "Minimum Collins star rating"),
+    (
+        "lango.quiz.max_rank",
+        "Only pick words ranked in the top N by frequency",
+    ),
+    (
+        "lango.lsp.about",
+        "Run as a language server (LSP) providing hover definitions and spell checking",
+    ),
+    (
+        "lango.lsp.examples",
+        "Example (Neovim):\n  vim.lsp.start({ name = 'lango', cmd = { 'lango', 'lsp', '--diagnostics' } })\n\nExample (Helix languages.toml):\n  [language-server.lango]\n  command = \"lango\"\n  args = [\"lsp\", \"--diagnostics\"]",
+    ),
+    (
+        "lango.lsp.diagnostics",
+        "Mark words missing from the dictionary as spelling problems",
+    ),
+    (
+        "lango.doctor.about",
+        "Print diagnostics (dictionary, config, cache, network)",
+    ),
+    (
+        "lango.doctor.fix",
+        "Build the spelling index for dictionaries that lack one",
+    ),
+];
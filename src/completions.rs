@@ -0,0 +1,86 @@
+use anyhow::Result;
+use clap_complete::Shell;
+use std::io::{self, Write};
+
+use lango::dict::DictionaryService;
+
+use crate::cli;
+use crate::dicts;
+
+/// 补全脚本获取候选词时调用的隐藏命令
+pub const COMPLETE_COMMAND: &str = "__complete";
+
+/// 输出已启用词库中以 `prefix` 开头的单词，每行一个
+///
+/// 补全时保持安静：没有词库或前缀为空时不输出任何内容。
+pub fn complete_words(prefix: &str) -> Result<()> {
+    if prefix.is_empty() || !dicts::any_installed() {
+        return Ok(());
+    }
+    let service = DictionaryService::new(dicts::open_selected(&[])?, None);
+    let mut out = io::stdout().lock();
+    for word in service.complete(prefix, 50)? {
+        writeln!(out, "{}", word)?;
+    }
+    Ok(())
+}
+
+/// 输出 shell 补全脚本
+///
+/// 在 clap 生成的静态脚本之后，为 bash / zsh / fish 追加单词补全：
+/// 输入查询词时调用 `lango __complete <前缀>` 获取词库中的候选词。
+pub fn print(shell: Shell) -> Result<()> {
+    let mut cmd = cli::command();
+    let name = cmd.get_name().to_string();
+
+    let mut out = io::stdout().lock();
+    clap_complete::generate(shell, &mut cmd, &name, &mut out);
+
+    let dynamic = match shell {
+        Shell::Bash => Some(BASH_WORDS),
+        Shell::Zsh => Some(ZSH_WORDS),
+        Shell::Fish => Some(FISH_WORDS),
+        _ => None,
+    };
+    if let Some(script) = dynamic {
+        writeln!(out)?;
+        out.write_all(script.as_bytes())?;
+    }
+
+    Ok(())
+}
+
+/// 所有前面的参数都是选项（尚未出现子命令或查询词）时补全单词
+const BASH_WORDS: &str = r#"# 查询词补全：从本地词库获取候选词
+_lango_with_words() {
+    _lango "$@"
+    local cur="${COMP_WORDS[COMP_CWORD]}"
+    [[ "$cur" == -* || -z "$cur" ]] && return
+    local i
+    for (( i = 1; i < COMP_CWORD; i++ )); do
+        [[ "${COMP_WORDS[i]}" != -* ]] && return
+    done
+    local IFS=$'\n'
+    COMPREPLY+=( $(lango __complete "$cur" 2>/dev/null) )
+}
+complete -F _lango_with_words -o bashdefault -o default lango
+"#;
+
+const ZSH_WORDS: &str = r#"# 查询词补全：从本地词库获取候选词
+_lango_with_words() {
+    _lango "$@"
+    [[ $PREFIX == -* || -z $PREFIX ]] && return
+    local w
+    for w in ${words[2,CURRENT-1]}; do
+        [[ $w != -* ]] && return
+    done
+    local -a candidates
+    candidates=(${(f)"$(lango __complete "$PREFIX" 2>/dev/null)"})
+    compadd -a candidates
+}
+compdef _lango_with_words lango
+"#;
+
+const FISH_WORDS: &str = r#"# 查询词补全：从本地词库获取候选词
+complete -c lango -n "__fish_use_subcommand" -f -a "(lango __complete (commandline -ct))"
+"#;
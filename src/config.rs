@@ -0,0 +1,36 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::fs;
+
+use lango::i18n::Lang;
+use lango::tr;
+
+use crate::setup;
+
+/// 用户配置，读取自 `setup::config_path()`（TOML 格式）
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// 查询未命中且最佳拼写建议足够接近时，直接显示该词条
+    pub auto_correct: bool,
+    /// 界面语言（`zh-CN` 或 `en`），未设置时根据环境变量选择
+    pub lang: Option<Lang>,
+    /// 播放发音的命令（如 `mpv --really-quiet`），`{}` 替换为音频文件路径；
+    /// 未设置时依次尝试常见播放器
+    pub player: Option<String>,
+}
+
+impl Config {
+    /// 加载配置，文件不存在时使用默认值
+    pub fn load() -> Result<Self> {
+        let path = setup::config_path()?;
+        let data = match fs::read_to_string(&path) {
+            Ok(data) => data,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => {
+                return Err(e).with_context(|| tr!("config.read_failed", path.display()));
+            }
+        };
+        toml::from_str(&data).with_context(|| tr!("config.malformed", path.display()))
+    }
+}
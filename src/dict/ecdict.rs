@@ -1,24 +1,405 @@
 use anyhow::{Context, Result};
 use rusqlite::Connection;
-use std::path::PathBuf;
+use rusqlite::functions::FunctionFlags;
+use rusqlite::types::Value;
+use std::path::Path;
 
-use super::Dictionary;
-use crate::types::{DataSource, DictionaryEntry};
+use super::spell::{self, Suggestion};
+use super::{Dictionary, LocalDictionary};
+use crate::tr;
+use crate::types::{DataSource, DictionaryEntry, SearchHit, WordFilter, WordInfo, WordPattern};
+
+/// 词频排名：取 frq 与 bnc 中较靠前的非零值
+const RANK_EXPR: &str =
+    "COALESCE(MIN(NULLIF(frq, 0), NULLIF(bnc, 0)), NULLIF(frq, 0), NULLIF(bnc, 0))";
 
 /// ECDICT 本地 SQLite 词典
 pub struct EcdictDictionary {
     conn: Connection,
+    name: String,
 }
 
 impl EcdictDictionary {
-    pub fn open(db_path: &PathBuf) -> Result<Self> {
+    /// 打开 ECDICT 格式的 SQLite 词库，名称默认为 `DEFAULT_DICT`
+    pub fn open(db_path: impl AsRef<Path>) -> Result<Self> {
+        let db_path = db_path.as_ref();
         let conn = Connection::open(db_path)
-            .with_context(|| format!("无法打开词库: {}", db_path.display()))?;
+            .with_context(|| tr!("setup.open_db_failed", db_path.display()))?;
 
         // 性能优化
         conn.execute_batch("PRAGMA journal_mode=WAL; PRAGMA cache_size=8000;")?;
 
-        Ok(Self { conn })
+        Ok(Self {
+            conn,
+            name: super::DEFAULT_DICT.to_string(),
+        })
+    }
+
+    /// 设置词库名称（用于区分多个已安装的词库）
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// 词库中是否已有当前格式的拼写索引（缺少 `tail` 列的旧索引需要重建）
+    pub fn has_spell_index(&self) -> Result<bool> {
+        let columns: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('lango_spell') WHERE name = 'tail'",
+            [],
+            |row| row.get(0),
+        )?;
+        Ok(columns > 0)
+    }
+
+    /// 建立拼写索引：为每个单词预先计算 Soundex 键，供拼写建议使用
+    ///
+    /// 索引保存在词库内的 `lango_spell` 表中，已有的索引会被替换。
+    /// 完整的 ECDICT 约有 70 万个单词，`progress` 以（已处理数, 总数）报告进度。
+    pub fn build_spell_index(&self, progress: &mut dyn FnMut(usize, usize)) -> Result<()> {
+        let total: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM stardict WHERE word NOT LIKE '% %'",
+            [],
+            |row| row.get(0),
+        )?;
+        let total = total as usize;
+
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute_batch(
+            "DROP TABLE IF EXISTS lango_spell;
+             CREATE TABLE lango_spell (key TEXT NOT NULL, tail TEXT NOT NULL, word TEXT NOT NULL, rank INTEGER);",
+        )?;
+        {
+            let mut select = tx.prepare(&format!(
+                "SELECT word, {} FROM stardict WHERE word NOT LIKE '% %'",
+                RANK_EXPR
+            ))?;
+            let mut insert = tx.prepare(
+                "INSERT INTO lango_spell (key, tail, word, rank) VALUES (?1, ?2, ?3, ?4)",
+            )?;
+            let mut rows = select.query([])?;
+            let mut done = 0;
+            while let Some(row) = rows.next()? {
+                done += 1;
+                if done % 10_000 == 0 {
+                    progress(done, total);
+                }
+                let word: String = row.get(0)?;
+                let rank: Option<i64> = row.get(1)?;
+                // 仅索引纯字母单词
+                if !word.chars().all(|c| c.is_ascii_alphabetic()) {
+                    continue;
+                }
+                if let Some(key) = spell::soundex(&word) {
+                    let tail = spell::soundex_tail(&key);
+                    insert.execute(rusqlite::params![key, tail, word, rank])?;
+                }
+            }
+        }
+        tx.execute_batch(
+            "CREATE INDEX lango_spell_key ON lango_spell (key);
+             CREATE INDEX lango_spell_tail ON lango_spell (tail);",
+        )?;
+        tx.commit()?;
+        progress(total, total);
+        Ok(())
+    }
+
+    /// 词头匹配的 SQL 条件（`?1` 为模式），正则模式会注册 `regexp` 函数
+    fn pattern_condition(&self, pattern: &WordPattern) -> Result<(&'static str, String)> {
+        Ok(match pattern {
+            WordPattern::Glob(glob) => ("word LIKE ?1 ESCAPE '\\'", glob_to_like(glob)),
+            WordPattern::Regex(re) => {
+                let regex = regex::RegexBuilder::new(re)
+                    .case_insensitive(true)
+                    .build()
+                    .with_context(|| tr!("hits.invalid_regex", re))?;
+                // SQLite 将 `X REGEXP Y` 转为 regexp(Y, X)，模式已预编译，忽略第一个参数
+                self.conn.create_scalar_function(
+                    "regexp",
+                    2,
+                    FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+                    move |ctx| {
+                        let text = ctx.get::<String>(1)?;
+                        Ok(regex.is_match(&text))
+                    },
+                )?;
+                ("word REGEXP ?1", re.clone())
+            }
+        })
+    }
+}
+
+impl LocalDictionary for EcdictDictionary {
+    fn search_phrases(
+        &self,
+        pattern: &[String],
+        any_order: bool,
+        limit: usize,
+    ) -> Result<Vec<SearchHit>> {
+        let tokens = phrase_tokens(pattern);
+        let literals: Vec<&String> = tokens.iter().filter(|t| t.as_str() != "*").collect();
+        if literals.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // 先用 LIKE 按整词筛出候选，再在内存中做精确匹配
+        let mut sql = String::from("SELECT word, translation FROM stardict WHERE word LIKE '% %'");
+        let mut params = Vec::new();
+        for literal in &literals {
+            sql.push_str(" AND (' ' || word || ' ') LIKE ? ESCAPE '\\'");
+            params.push(format!("% {} %", escape_like(literal)));
+        }
+        sql.push_str(" ORDER BY length(word), word");
+
+        let mut stmt = self.conn.prepare(&sql)?;
+        let rows = stmt.query_map(rusqlite::params_from_iter(params.iter()), |row| {
+            Ok(SearchHit {
+                word: row.get(0)?,
+                translation: row.get(1)?,
+            })
+        })?;
+
+        let mut hits = Vec::new();
+        for hit in rows {
+            let hit = hit?;
+            let words: Vec<String> = hit
+                .word
+                .to_lowercase()
+                .split_whitespace()
+                .map(str::to_string)
+                .collect();
+            let matched = if any_order {
+                literals.iter().all(|l| words.contains(l))
+            } else {
+                (0..words.len()).any(|start| match_phrase(&tokens, &words[start..]))
+            };
+            if matched {
+                hits.push(hit);
+                if hits.len() >= limit {
+                    break;
+                }
+            }
+        }
+
+        Ok(hits)
+    }
+
+    fn complete_prefix(&self, prefix: &str, limit: usize) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare_cached(&format!(
+            "SELECT word FROM stardict WHERE word LIKE ?1 ESCAPE '\\' AND word NOT LIKE '% %' \
+             ORDER BY {} IS NULL, {}, word LIMIT ?2",
+            RANK_EXPR, RANK_EXPR
+        ))?;
+        let pattern = format!("{}%", escape_like(prefix));
+        let words = stmt
+            .query_map(rusqlite::params![pattern, limit as i64], |row| row.get(0))?
+            .collect::<rusqlite::Result<Vec<String>>>()?;
+        Ok(words)
+    }
+
+    fn match_words(
+        &self,
+        pattern: &WordPattern,
+        len: Option<usize>,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(usize, Vec<SearchHit>)> {
+        let (condition, param) = self.pattern_condition(pattern)?;
+        let len_condition = if len.is_some() {
+            " AND length(word) = ?2"
+        } else {
+            " AND ?2 IS NULL"
+        };
+        let len = len.map(|n| n as i64);
+
+        let total: i64 = self.conn.query_row(
+            &format!(
+                "SELECT COUNT(*) FROM stardict WHERE {}{}",
+                condition, len_condition
+            ),
+            rusqlite::params![param, len],
+            |row| row.get(0),
+        )?;
+
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT word, translation FROM stardict WHERE {}{} \
+             ORDER BY (frq IS NULL OR frq = 0), frq, word LIMIT ?3 OFFSET ?4",
+            condition, len_condition
+        ))?;
+        let hits = stmt
+            .query_map(
+                rusqlite::params![param, len, limit as i64, offset as i64],
+                |row| {
+                    Ok(SearchHit {
+                        word: row.get(0)?,
+                        translation: row.get(1)?,
+                    })
+                },
+            )?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+
+        Ok((total as usize, hits))
+    }
+
+    fn matching_words(&self, pattern: &WordPattern, len: Option<usize>) -> Result<Vec<String>> {
+        let (condition, param) = self.pattern_condition(pattern)?;
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT word FROM stardict WHERE {} AND (?2 IS NULL OR length(word) = ?2) \
+             ORDER BY (frq IS NULL OR frq = 0), frq, word",
+            condition
+        ))?;
+        let words = stmt
+            .query_map(rusqlite::params![param, len.map(|n| n as i64)], |row| {
+                row.get(0)
+            })?
+            .collect::<rusqlite::Result<Vec<String>>>()?;
+        Ok(words)
+    }
+
+    fn suggest(&self, query: &str, limit: usize) -> Result<Vec<Suggestion>> {
+        // 候选词及其词频排名
+        let mut candidates: Vec<(String, Option<i64>)> = Vec::new();
+
+        // 先尝试前缀匹配（利用索引，快速）
+        let mut stmt = self.conn.prepare_cached(&format!(
+            "SELECT word, {} FROM stardict WHERE word LIKE ?1 COLLATE NOCASE \
+             ORDER BY length(word) LIMIT ?2",
+            RANK_EXPR
+        ))?;
+        let prefix_pattern = format!("{}%", query);
+        for row in stmt.query_map(rusqlite::params![prefix_pattern, limit * 2], |row| {
+            Ok((row.get(0)?, row.get(1)?))
+        })? {
+            let (word, rank) = row?;
+            push_candidate(&mut candidates, word, rank);
+        }
+
+        // 读音相近的词（拼写索引）：完整键覆盖中间字母拼错，
+        // 去掉首字母的键覆盖首字母拼错（如 fone → phone）。
+        // 索引需在安装词库时或通过 `lango doctor --fix` 建立，未建立时跳过
+        if let Some(key) = spell::soundex(query) {
+            if self.has_spell_index()? {
+                let mut stmt = self.conn.prepare_cached(
+                    "SELECT word, rank FROM lango_spell WHERE key = ?1 \
+                     UNION SELECT word, rank FROM (SELECT word, rank FROM lango_spell \
+                     WHERE tail = ?2 ORDER BY rank IS NULL, rank LIMIT ?3)",
+                )?;
+                let tail = spell::soundex_tail(&key);
+                for row in stmt.query_map(rusqlite::params![key, tail, limit * 20], |row| {
+                    Ok((row.get(0)?, row.get(1)?))
+                })? {
+                    let (word, rank) = row?;
+                    push_candidate(&mut candidates, word, rank);
+                }
+            }
+        }
+
+        // 如果候选不够，用 LIKE 模糊搜索补充
+        if candidates.len() < limit {
+            let mut stmt2 = self.conn.prepare_cached(&format!(
+                "SELECT word, {} FROM stardict WHERE word LIKE ?1 COLLATE NOCASE \
+                 ORDER BY length(word) LIMIT ?2",
+                RANK_EXPR
+            ))?;
+            let fuzzy_pattern = format!("%{}%", query);
+            for row in stmt2.query_map(rusqlite::params![fuzzy_pattern, limit * 3], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })? {
+                let (word, rank) = row?;
+                push_candidate(&mut candidates, word, rank);
+            }
+        }
+
+        // 按加权编辑距离与词频综合排序，取最接近的
+        let mut suggestions: Vec<Suggestion> = candidates
+            .into_iter()
+            .map(|(word, rank)| Suggestion::new(query, word, rank))
+            .collect();
+        suggestions.sort_by(|a, b| {
+            a.score()
+                .total_cmp(&b.score())
+                .then_with(|| a.word.cmp(&b.word))
+        });
+        suggestions.truncate(limit);
+
+        Ok(suggestions)
+    }
+
+    fn contains(&self, word: &str) -> Result<bool> {
+        let mut stmt = self.conn.prepare_cached(
+            "SELECT EXISTS(SELECT 1 FROM stardict WHERE word = ?1 COLLATE NOCASE)",
+        )?;
+        Ok(stmt.query_row([word], |row| row.get(0))?)
+    }
+
+    fn word_info(&self, word: &str) -> Result<Option<WordInfo>> {
+        let mut stmt = self.conn.prepare_cached(&format!(
+            "SELECT {} FROM stardict WHERE word = ?1 COLLATE NOCASE LIMIT 1",
+            WORD_INFO_COLUMNS
+        ))?;
+        match stmt.query_row([word], word_info_from_row) {
+            Ok(info) => Ok(Some(info)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn tagged_words(&self, tag: &str) -> Result<Vec<WordInfo>> {
+        let mut stmt = self.conn.prepare_cached(&format!(
+            "SELECT {} FROM stardict WHERE (' ' || tag || ' ') LIKE ?1 ESCAPE '\\' \
+             AND word NOT LIKE '% %' ORDER BY {} IS NULL, {}, word",
+            WORD_INFO_COLUMNS, RANK_EXPR, RANK_EXPR
+        ))?;
+        let pattern = format!("% {} %", escape_like(tag));
+        let words = stmt
+            .query_map([pattern], word_info_from_row)?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(words)
+    }
+
+    fn count_words(&self, filter: &WordFilter) -> Result<usize> {
+        let (clause, params) = filter_clause(filter);
+        let mut stmt = self
+            .conn
+            .prepare_cached(&format!("SELECT COUNT(*) FROM stardict WHERE {}", clause))?;
+        let count: i64 = stmt.query_row(rusqlite::params_from_iter(params), |row| row.get(0))?;
+        Ok(count as usize)
+    }
+
+    fn nth_word(&self, filter: &WordFilter, n: usize) -> Result<Option<WordInfo>> {
+        let (clause, mut params) = filter_clause(filter);
+        params.push(Value::Integer(n as i64));
+        let mut stmt = self.conn.prepare_cached(&format!(
+            "SELECT {} FROM stardict WHERE {} ORDER BY id LIMIT 1 OFFSET ?",
+            WORD_INFO_COLUMNS, clause
+        ))?;
+        match stmt.query_row(rusqlite::params_from_iter(params), word_info_from_row) {
+            Ok(info) => Ok(Some(info)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn nearest_by_rank(
+        &self,
+        filter: &WordFilter,
+        rank: i64,
+        exclude: &str,
+        limit: usize,
+    ) -> Result<Vec<WordInfo>> {
+        let (clause, mut params) = filter_clause(filter);
+        params.push(Value::Text(exclude.to_string()));
+        params.push(Value::Integer(rank));
+        params.push(Value::Integer(limit as i64));
+        let mut stmt = self.conn.prepare_cached(&format!(
+            "SELECT {} FROM stardict WHERE {} AND word <> ? COLLATE NOCASE AND {} IS NOT NULL \
+             ORDER BY ABS({} - ?) LIMIT ?",
+            WORD_INFO_COLUMNS, clause, RANK_EXPR, RANK_EXPR
+        ))?;
+        let words = stmt
+            .query_map(rusqlite::params_from_iter(params), word_info_from_row)?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(words)
     }
 }
 
@@ -39,7 +420,7 @@ impl Dictionary for EcdictDictionary {
                 exchange: row.get(5)?,
                 tag: row.get(6)?,
                 examples: Vec::new(),
-                source: DataSource::Local,
+                source: DataSource::Local(self.name.clone()),
             })
         });
 
@@ -51,47 +432,168 @@ impl Dictionary for EcdictDictionary {
     }
 
     fn fuzzy_search(&self, query: &str, limit: usize) -> Result<Vec<String>> {
-        // 先尝试前缀匹配（利用索引，快速）
-        let mut stmt = self.conn.prepare_cached(
-            "SELECT word FROM stardict WHERE word LIKE ?1 COLLATE NOCASE ORDER BY length(word) LIMIT ?2"
-        )?;
-        let prefix_pattern = format!("{}%", query);
-        let mut results: Vec<String> = stmt
-            .query_map(rusqlite::params![prefix_pattern, limit * 2], |row| {
-                row.get(0)
-            })?
-            .filter_map(|r| r.ok())
-            .collect();
+        Ok(self
+            .suggest(query, limit)?
+            .into_iter()
+            .map(|s| s.word)
+            .collect())
+    }
 
-        // 如果前缀匹配不够，用 LIKE 模糊搜索补充
-        if results.len() < limit {
-            let mut stmt2 = self.conn.prepare_cached(
-                "SELECT word FROM stardict WHERE word LIKE ?1 COLLATE NOCASE ORDER BY length(word) LIMIT ?2"
-            )?;
-            let fuzzy_pattern = format!("%{}%", query);
-            let extra: Vec<String> = stmt2
-                .query_map(rusqlite::params![fuzzy_pattern, limit * 3], |row| {
-                    row.get(0)
-                })?
-                .filter_map(|r| r.ok())
-                .filter(|w: &String| !results.contains(w))
-                .collect();
-            results.extend(extra);
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// 将筛选条件转为 WHERE 子句与参数：只取有中文释义的单个单词
+fn filter_clause(filter: &WordFilter) -> (String, Vec<Value>) {
+    let mut conditions = vec![
+        "word NOT LIKE '% %'".to_string(),
+        "translation IS NOT NULL AND translation <> ''".to_string(),
+    ];
+    let mut params = Vec::new();
+
+    if let Some(ref tag) = filter.tag {
+        conditions.push("(' ' || tag || ' ') LIKE ? ESCAPE '\\'".to_string());
+        params.push(Value::Text(format!("% {} %", escape_like(tag))));
+    }
+    if filter.oxford {
+        conditions.push("oxford = 1".to_string());
+    }
+    if let Some(stars) = filter.min_collins {
+        conditions.push("collins >= ?".to_string());
+        params.push(Value::Integer(stars as i64));
+    }
+    if let Some(rank) = filter.max_rank {
+        conditions.push(format!("{} <= ?", RANK_EXPR));
+        params.push(Value::Integer(rank));
+    }
+    if filter.tag.is_none()
+        && !filter.oxford
+        && filter.min_collins.is_none()
+        && filter.max_rank.is_none()
+    {
+        conditions.push("(tag <> '' OR collins > 0 OR oxford = 1)".to_string());
+    }
+
+    (conditions.join(" AND "), params)
+}
+
+/// `word_info_from_row` 读取的列
+const WORD_INFO_COLUMNS: &str = "word, phonetic, translation, tag, exchange, collins, oxford, \
+     NULLIF(bnc, 0), NULLIF(frq, 0)";
+
+fn word_info_from_row(row: &rusqlite::Row) -> rusqlite::Result<WordInfo> {
+    Ok(WordInfo {
+        word: row.get(0)?,
+        phonetic: row.get(1)?,
+        translation: row.get(2)?,
+        tag: row.get(3)?,
+        exchange: row.get(4)?,
+        collins: row.get::<_, Option<i64>>(5)?.unwrap_or(0).clamp(0, 5) as u8,
+        oxford: row.get::<_, Option<i64>>(6)?.unwrap_or(0) != 0,
+        bnc: row.get(7)?,
+        frq: row.get(8)?,
+    })
+}
+
+/// 添加候选词（忽略大小写去重）
+fn push_candidate(candidates: &mut Vec<(String, Option<i64>)>, word: String, rank: Option<i64>) {
+    if !candidates
+        .iter()
+        .any(|(w, _)| w.eq_ignore_ascii_case(&word))
+    {
+        candidates.push((word, rank));
+    }
+}
+
+/// 转义 LIKE 通配符（配合 `ESCAPE '\'`）
+fn escape_like(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+/// 将 `?` / `*` 通配符转换为 LIKE 模式
+fn glob_to_like(glob: &str) -> String {
+    escape_like(glob).replace('?', "_").replace('*', "%")
+}
+
+/// 将查询参数拆分为小写单词，`"take off"` 与 `take off` 等价
+fn phrase_tokens(pattern: &[String]) -> Vec<String> {
+    pattern
+        .iter()
+        .flat_map(|arg| arg.split_whitespace())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// 判断 `words` 的前缀是否匹配 `tokens`，`*` 匹配零个或多个单词
+fn match_phrase(tokens: &[String], words: &[String]) -> bool {
+    match tokens.split_first() {
+        None => true,
+        Some((first, rest)) if first == "*" => {
+            (0..=words.len()).any(|skip| match_phrase(rest, &words[skip..]))
         }
+        Some((first, rest)) => match words.split_first() {
+            Some((word, remaining)) if word == first => match_phrase(rest, remaining),
+            _ => false,
+        },
+    }
+}
 
-        // 用编辑距离排序，取最接近的
-        use strsim::levenshtein;
-        results.sort_by_key(|w| levenshtein(&w.to_lowercase(), &query.to_lowercase()));
-        results.truncate(limit);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        Ok(results)
+    fn strings(s: &str) -> Vec<String> {
+        s.split_whitespace().map(str::to_string).collect()
     }
 
-    fn is_available(&self) -> bool {
-        true
+    #[test]
+    fn glob_to_like_translates_wildcards() {
+        assert_eq!(glob_to_like("c?t"), "c_t");
+        assert_eq!(glob_to_like("*tion"), "%tion");
+        assert_eq!(glob_to_like("un*able?"), "un%able_");
     }
 
-    fn name(&self) -> &str {
-        "ECDICT"
+    #[test]
+    fn glob_to_like_escapes_like_metacharacters() {
+        assert_eq!(glob_to_like("100%"), "100\\%");
+        assert_eq!(glob_to_like("snake_case*"), "snake\\_case%");
+        assert_eq!(glob_to_like("a\\b"), "a\\\\b");
+    }
+
+    #[test]
+    fn phrase_tokens_split_quoted_arguments() {
+        let quoted = vec!["Take * into account".to_string()];
+        assert_eq!(phrase_tokens(&quoted), strings("take * into account"));
+
+        let unquoted = strings("take off");
+        assert_eq!(phrase_tokens(&unquoted), strings("take off"));
+        assert_eq!(phrase_tokens(&["take off".to_string()]), unquoted);
+    }
+
+    #[test]
+    fn match_phrase_requires_consecutive_words() {
+        let words = strings("take off one's hat");
+        assert!(match_phrase(&strings("take off"), &words));
+        assert!(!match_phrase(&strings("take hat"), &words));
+        assert!(!match_phrase(&strings("off"), &words));
+    }
+
+    #[test]
+    fn match_phrase_wildcard_spans_any_number_of_words() {
+        let words = strings("take something into account");
+        assert!(match_phrase(&strings("take * into account"), &words));
+        assert!(match_phrase(&strings("take * account"), &words));
+        assert!(match_phrase(
+            &strings("take something * into account"),
+            &words
+        ));
+        assert!(!match_phrase(&strings("take * off"), &words));
     }
 }
@@ -1,32 +1,170 @@
-use crate::types::{DictionaryEntry, LookupOptions, LookupResult};
+use crate::types::{
+    DataSource, DictionaryEntry, LookupOptions, LookupResult, SearchHit, SourceResult, WordFilter,
+    WordInfo, WordPattern,
+};
 use anyhow::Result;
+use std::collections::HashSet;
 
 pub mod ecdict;
 pub mod online;
+mod spell;
+
+pub use spell::Suggestion;
+
+/// 默认词库名称（命令行工具将其安装为 `stardict.db`）
+pub const DEFAULT_DICT: &str = "ecdict";
+
+/// 自动纠正允许的最大加权编辑距离（约一次误触或换位）
+const AUTO_CORRECT_MAX_DISTANCE: f32 = 1.0;
+/// 自动纠正要求的最低词频排名
+const AUTO_CORRECT_MAX_RANK: i64 = 20_000;
+
+/// 拼写建议是否可信到可以直接显示
+fn is_confident(suggestion: &Suggestion) -> bool {
+    suggestion.distance <= AUTO_CORRECT_MAX_DISTANCE
+        && suggestion.rank.is_some_and(|r| r <= AUTO_CORRECT_MAX_RANK)
+}
 
 /// 词典后端 trait
-#[allow(dead_code)]
 pub trait Dictionary {
+    /// 精确查询单词或词组，未收录时返回 `Ok(None)`
     fn lookup(&self, query: &str) -> Result<Option<DictionaryEntry>>;
+    /// 拼写建议，最多返回 `limit` 个候选词
     fn fuzzy_search(&self, query: &str, limit: usize) -> Result<Vec<String>>;
     fn is_available(&self) -> bool;
     fn name(&self) -> &str;
 }
 
+/// 本地词库后端：在 [`Dictionary`] 之上提供词头检索与单词元数据
+///
+/// 除 [`Dictionary`] 的方法外均有默认实现（返回空结果或由 `lookup` / `fuzzy_search`
+/// 推出），自定义词库只需实现所支持的部分即可接入 [`DictionaryService`]。
+pub trait LocalDictionary: Dictionary {
+    /// 词库是否收录该词（忽略大小写）
+    fn contains(&self, word: &str) -> Result<bool> {
+        Ok(self.lookup(word)?.is_some())
+    }
+
+    /// 查询单词的词频、考试标签等元数据
+    fn word_info(&self, _word: &str) -> Result<Option<WordInfo>> {
+        Ok(None)
+    }
+
+    /// 生成拼写建议，按综合得分从优到劣排序
+    fn suggest(&self, query: &str, limit: usize) -> Result<Vec<Suggestion>> {
+        let mut suggestions: Vec<Suggestion> = self
+            .fuzzy_search(query, limit)?
+            .into_iter()
+            .map(|word| Suggestion::new(query, word, None))
+            .collect();
+        suggestions.sort_by(|a, b| a.score().total_cmp(&b.score()));
+        Ok(suggestions)
+    }
+
+    /// 搜索多词词条
+    ///
+    /// `pattern` 为空格分隔的单词，`*` 匹配任意个单词；默认要求单词按顺序
+    /// 连续出现在词条中的任意位置，`any_order` 时只要求所有单词都出现。
+    fn search_phrases(
+        &self,
+        _pattern: &[String],
+        _any_order: bool,
+        _limit: usize,
+    ) -> Result<Vec<SearchHit>> {
+        Ok(Vec::new())
+    }
+
+    /// 以 `prefix` 开头的单个单词，常用词在前（用于 shell 补全）
+    fn complete_prefix(&self, _prefix: &str, _limit: usize) -> Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    /// 按通配符或正则匹配词头，返回匹配总数与 `offset` 起的至多 `limit` 条结果
+    ///
+    /// 结果按词频排序（无词频的排在最后），便于优先看到常用词。
+    fn match_words(
+        &self,
+        _pattern: &WordPattern,
+        _len: Option<usize>,
+        _offset: usize,
+        _limit: usize,
+    ) -> Result<(usize, Vec<SearchHit>)> {
+        Ok((0, Vec::new()))
+    }
+
+    /// 匹配的全部词头（不含释义），排序同 `match_words`，用于合并多个词库的结果
+    fn matching_words(&self, _pattern: &WordPattern, _len: Option<usize>) -> Result<Vec<String>> {
+        Ok(Vec::new())
+    }
+
+    /// 带有指定考试标签（如 `cet4`）的单个单词，常用词在前
+    fn tagged_words(&self, _tag: &str) -> Result<Vec<WordInfo>> {
+        Ok(Vec::new())
+    }
+
+    /// 满足筛选条件的单词数
+    fn count_words(&self, _filter: &WordFilter) -> Result<usize> {
+        Ok(0)
+    }
+
+    /// 满足筛选条件的第 `n` 个单词（按词条 id 排序，从 0 开始）
+    fn nth_word(&self, _filter: &WordFilter, _n: usize) -> Result<Option<WordInfo>> {
+        Ok(None)
+    }
+
+    /// 满足筛选条件、词频排名与 `rank` 最接近的单词（不含 `exclude`），用于生成干扰项
+    fn nearest_by_rank(
+        &self,
+        _filter: &WordFilter,
+        _rank: i64,
+        _exclude: &str,
+        _limit: usize,
+    ) -> Result<Vec<WordInfo>> {
+        Ok(Vec::new())
+    }
+}
+
 /// 词典查询服务：编排本地 + 在线查询
 pub struct DictionaryService {
-    local: Option<ecdict::EcdictDictionary>,
+    /// 本地词库，按查询优先级排列
+    locals: Vec<Box<dyn LocalDictionary>>,
     online: Option<online::OnlineDictionary>,
 }
 
 impl DictionaryService {
+    /// `locals` 按查询优先级排列；`online` 为空时不使用在线词典
     pub fn new(
-        local: Option<ecdict::EcdictDictionary>,
+        locals: Vec<Box<dyn LocalDictionary>>,
         online: Option<online::OnlineDictionary>,
     ) -> Self {
-        Self { local, online }
+        Self { locals, online }
+    }
+
+    /// 启用在线词典
+    pub fn with_online(mut self, online: online::OnlineDictionary) -> Self {
+        self.online = Some(online);
+        self
+    }
+
+    /// 在线词典（未启用时为 None）
+    pub fn online(&self) -> Option<&online::OnlineDictionary> {
+        self.online.as_ref()
+    }
+
+    /// 只在本地词库中精确查询：不生成拼写建议，也不访问在线词典
+    ///
+    /// 用于编辑器悬停这类需要立即返回的场景。
+    pub fn lookup_exact(&self, word: &str) -> Result<Option<DictionaryEntry>> {
+        let word = word.trim().to_lowercase();
+        for local in &self.locals {
+            if let Some(entry) = local.lookup(&word)? {
+                return Ok(Some(entry));
+            }
+        }
+        Ok(None)
     }
 
+    /// 查询单词：本地优先，未找到时给出拼写建议或回退到在线词典
     pub fn lookup(&self, query: &str, options: &LookupOptions) -> Result<LookupResult> {
         let query = query.trim().to_lowercase();
 
@@ -41,16 +179,56 @@ impl DictionaryService {
             return Ok(LookupResult::NotFound);
         }
 
-        // 默认模式：先查本地
-        if let Some(ref local) = self.local {
-            if let Some(mut entry) = local.lookup(&query)? {
+        // 默认模式：依次查询本地词库，取第一个命中
+        if let Some(entry) = self.lookup_local(&query, options)? {
+            return Ok(LookupResult::Found(entry));
+        }
+
+        // 本地未找到 → 合并各词库的拼写建议
+        let suggestions = self.suggest(&query, 5)?;
+
+        // 自动纠正：最佳建议足够接近且足够常用时直接显示
+        if options.auto_correct {
+            if let Some(best) = suggestions.first().filter(|s| is_confident(s)) {
+                if let Some(entry) = self.lookup_local(&best.word, options)? {
+                    return Ok(LookupResult::Corrected(entry));
+                }
+            }
+        }
+
+        if !suggestions.is_empty() {
+            return Ok(LookupResult::Suggestions(
+                suggestions.into_iter().map(|s| s.word).collect(),
+            ));
+        }
+
+        // 本地没结果，尝试在线兜底
+        if let Some(ref online) = self.online {
+            if let Some(mut entry) = online.lookup(&query)? {
+                entry.examples.truncate(options.max_examples);
+                return Ok(LookupResult::Found(entry));
+            }
+        }
+
+        Ok(LookupResult::NotFound)
+    }
+
+    /// 依次查询本地词库，必要时用在线数据补充英文释义与例句
+    fn lookup_local(
+        &self,
+        query: &str,
+        options: &LookupOptions,
+    ) -> Result<Option<DictionaryEntry>> {
+        for local in &self.locals {
+            if let Some(mut entry) = local.lookup(query)? {
                 // 如果需要例句/英文释义但本地没有，尝试在线补充
                 let needs_online = (options.show_examples && entry.examples.is_empty())
                     || (options.show_english && entry.definition.is_none());
 
                 if needs_online {
                     if let Some(ref online) = self.online {
-                        if let Some(online_entry) = online.lookup(&query)? {
+                        // 补充数据失败（如被限流）时保留本地结果
+                        if let Ok(Some(online_entry)) = online.lookup(query) {
                             if entry.definition.is_none() {
                                 entry.definition = online_entry.definition;
                             }
@@ -62,24 +240,415 @@ impl DictionaryService {
                 }
 
                 entry.examples.truncate(options.max_examples);
-                return Ok(LookupResult::Found(entry));
+                return Ok(Some(entry));
             }
+        }
+        Ok(None)
+    }
 
-            // 本地未找到 → 模糊匹配
-            let suggestions = local.fuzzy_search(&query, 5)?;
-            if !suggestions.is_empty() {
-                return Ok(LookupResult::Suggestions(suggestions));
+    /// 任一本地词库是否收录该词
+    pub fn contains(&self, word: &str) -> Result<bool> {
+        for local in &self.locals {
+            if local.contains(word)? {
+                return Ok(true);
             }
         }
+        Ok(false)
+    }
 
-        // 本地没结果，尝试在线兜底
+    /// 在本地词库中按顺序查询单词元数据
+    pub fn word_info(&self, word: &str) -> Result<Option<WordInfo>> {
+        for local in &self.locals {
+            if let Some(info) = local.word_info(word)? {
+                return Ok(Some(info));
+            }
+        }
+        Ok(None)
+    }
+
+    /// 合并各本地词库中带有指定考试标签的单词，常用词在前
+    pub fn tagged_words(&self, tag: &str) -> Result<Vec<WordInfo>> {
+        let mut seen = HashSet::new();
+        let mut words: Vec<WordInfo> = Vec::new();
+        for local in &self.locals {
+            for info in local.tagged_words(tag)? {
+                if seen.insert(info.word.to_lowercase()) {
+                    words.push(info);
+                }
+            }
+        }
+        words.sort_by_key(|info| info.rank().unwrap_or(i64::MAX));
+        Ok(words)
+    }
+
+    /// 各本地词库中满足筛选条件的单词总数
+    pub fn count_words(&self, filter: &WordFilter) -> Result<usize> {
+        let mut total = 0;
+        for local in &self.locals {
+            total += local.count_words(filter)?;
+        }
+        Ok(total)
+    }
+
+    /// 满足筛选条件的第 `n` 个单词，多个词库按顺序拼接（与 [`count_words`](Self::count_words) 对应）
+    pub fn nth_word(&self, filter: &WordFilter, mut n: usize) -> Result<Option<WordInfo>> {
+        for local in &self.locals {
+            let count = local.count_words(filter)?;
+            if n < count {
+                return local.nth_word(filter, n);
+            }
+            n -= count;
+        }
+        Ok(None)
+    }
+
+    /// 合并各本地词库中词频排名与 `rank` 最接近的单词，由近到远排列
+    pub fn nearest_by_rank(
+        &self,
+        filter: &WordFilter,
+        rank: i64,
+        exclude: &str,
+        limit: usize,
+    ) -> Result<Vec<WordInfo>> {
+        let mut seen = HashSet::new();
+        let mut words: Vec<WordInfo> = Vec::new();
+        for local in &self.locals {
+            for info in local.nearest_by_rank(filter, rank, exclude, limit)? {
+                if seen.insert(info.word.to_lowercase()) {
+                    words.push(info);
+                }
+            }
+        }
+        words.sort_by_key(|info| info.rank().map_or(i64::MAX, |r| (r - rank).abs()));
+        words.truncate(limit);
+        Ok(words)
+    }
+
+    /// 词形还原：返回单词原形的元数据
+    ///
+    /// 优先使用 ECDICT exchange 字段中记录的原形（如 went → go）；
+    /// 词库未收录该形式时，按常见屈折后缀还原后再查询（如 cats → cat）。
+    pub fn lemmatize(&self, word: &str) -> Result<Option<WordInfo>> {
+        let word = word.to_lowercase();
+        if let Some(info) = self.word_info(&word)? {
+            if let Some(lemma) = info.lemma() {
+                if let Some(base) = self.word_info(lemma)? {
+                    return Ok(Some(base));
+                }
+            }
+            return Ok(Some(info));
+        }
+        for candidate in inflection_bases(&word) {
+            if let Some(info) = self.word_info(&candidate)? {
+                return Ok(Some(info));
+            }
+        }
+        Ok(None)
+    }
+
+    /// 合并各本地词库的拼写建议，按综合得分从优到劣排序
+    pub fn suggest(&self, query: &str, limit: usize) -> Result<Vec<Suggestion>> {
+        let mut suggestions: Vec<Suggestion> = Vec::new();
+        for local in &self.locals {
+            for suggestion in local.suggest(query, limit)? {
+                if !suggestions.iter().any(|s| s.word == suggestion.word) {
+                    suggestions.push(suggestion);
+                }
+            }
+        }
+        suggestions.sort_by(|a, b| a.score().total_cmp(&b.score()));
+        suggestions.truncate(limit);
+        Ok(suggestions)
+    }
+
+    /// 在所有本地词库中搜索词组，按词库顺序合并去重
+    pub fn search_phrases(
+        &self,
+        pattern: &[String],
+        any_order: bool,
+        limit: usize,
+    ) -> Result<Vec<SearchHit>> {
+        let mut hits: Vec<SearchHit> = Vec::new();
+        for local in &self.locals {
+            for hit in local.search_phrases(pattern, any_order, limit)? {
+                if !hits.iter().any(|h| h.word.eq_ignore_ascii_case(&hit.word)) {
+                    hits.push(hit);
+                }
+            }
+        }
+        hits.truncate(limit);
+        Ok(hits)
+    }
+
+    /// 合并各本地词库中以 `prefix` 开头的单词
+    pub fn complete(&self, prefix: &str, limit: usize) -> Result<Vec<String>> {
+        let mut words: Vec<String> = Vec::new();
+        for local in &self.locals {
+            for word in local.complete_prefix(prefix, limit)? {
+                if !words.contains(&word) {
+                    words.push(word);
+                }
+            }
+        }
+        words.truncate(limit);
+        Ok(words)
+    }
+
+    /// 在本地词库中按模式匹配词头，多个词库的结果按顺序合并去重后分页
+    pub fn match_words(
+        &self,
+        pattern: &WordPattern,
+        len: Option<usize>,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(usize, Vec<SearchHit>)> {
+        if self.locals.len() > 1 {
+            return self.match_words_merged(pattern, len, offset, limit);
+        }
+        let mut total = 0;
+        let mut hits = Vec::new();
+        for local in &self.locals {
+            // 跳过前面词库已占用的位置，计算本词库内的偏移
+            let local_offset = offset.saturating_sub(total);
+            let remaining = limit - hits.len();
+            let (count, mut page) = local.match_words(pattern, len, local_offset, remaining)?;
+            total += count;
+            hits.append(&mut page);
+        }
+        Ok((total, hits))
+    }
+
+    /// 多个词库时按词库顺序合并词头并去重（同一词头取第一个词库的释义），再分页
+    fn match_words_merged(
+        &self,
+        pattern: &WordPattern,
+        len: Option<usize>,
+        offset: usize,
+        limit: usize,
+    ) -> Result<(usize, Vec<SearchHit>)> {
+        let mut seen = HashSet::new();
+        let mut total = 0;
+        let mut hits = Vec::new();
+        for local in &self.locals {
+            for word in local.matching_words(pattern, len)? {
+                if !seen.insert(word.to_lowercase()) {
+                    continue;
+                }
+                if total >= offset && hits.len() < limit {
+                    let translation = local.word_info(&word)?.and_then(|info| info.translation);
+                    hits.push(SearchHit { word, translation });
+                }
+                total += 1;
+            }
+        }
+        Ok((total, hits))
+    }
+
+    /// 查询所有后端并分别返回结果，不做合并
+    pub fn lookup_all(&self, query: &str, options: &LookupOptions) -> Vec<SourceResult> {
+        let query = query.trim().to_lowercase();
+        let mut results = Vec::new();
+
+        for local in &self.locals {
+            results.push(source_result(
+                DataSource::Local(local.name().to_string()),
+                local.lookup(&query),
+                options,
+            ));
+        }
         if let Some(ref online) = self.online {
-            if let Some(mut entry) = online.lookup(&query)? {
-                entry.examples.truncate(options.max_examples);
-                return Ok(LookupResult::Found(entry));
+            results.push(source_result(
+                DataSource::Online,
+                online.lookup(&query),
+                options,
+            ));
+        }
+
+        results
+    }
+}
+
+/// 按英语屈折变化规则推测的原形候选，按可能性排列
+fn inflection_bases(word: &str) -> Vec<String> {
+    const RULES: &[(&str, &str)] = &[
+        ("ies", "y"),
+        ("ied", "y"),
+        ("ier", "y"),
+        ("iest", "y"),
+        ("ves", "f"),
+        ("ves", "fe"),
+        ("es", ""),
+        ("s", ""),
+        ("ed", ""),
+        ("ed", "e"),
+        ("ing", ""),
+        ("ing", "e"),
+        ("er", ""),
+        ("er", "e"),
+        ("est", ""),
+        ("est", "e"),
+    ];
+
+    let mut bases = Vec::new();
+    for (suffix, replacement) in RULES {
+        let Some(stem) = word.strip_suffix(suffix) else {
+            continue;
+        };
+        if stem.len() < 2 {
+            continue;
+        }
+        bases.push(format!("{}{}", stem, replacement));
+        // 双写辅音：stopped → stop, bigger → big（按字符比较，词干可能含非 ASCII 字符）
+        let mut tail = stem.char_indices().rev();
+        if let (Some((last_at, last)), Some((_, before))) = (tail.next(), tail.next()) {
+            if replacement.is_empty()
+                && stem.chars().count() >= 3
+                && last == before
+                && last.is_ascii_alphabetic()
+                && !"aeiou".contains(last)
+            {
+                bases.push(stem[..last_at].to_string());
             }
         }
+    }
+    bases
+}
 
-        Ok(LookupResult::NotFound)
+fn source_result(
+    source: DataSource,
+    result: Result<Option<DictionaryEntry>>,
+    options: &LookupOptions,
+) -> SourceResult {
+    match result {
+        Ok(mut entry) => {
+            if let Some(ref mut e) = entry {
+                e.examples.truncate(options.max_examples);
+            }
+            SourceResult {
+                source,
+                entry,
+                error: None,
+            }
+        }
+        Err(e) => SourceResult {
+            source,
+            entry: None,
+            error: Some(e.to_string()),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 只实现 `Dictionary` 的最小词库，其余方法使用 `LocalDictionary` 的默认实现
+    struct WordList(&'static [&'static str]);
+
+    impl Dictionary for WordList {
+        fn lookup(&self, query: &str) -> Result<Option<DictionaryEntry>> {
+            Ok(self
+                .0
+                .iter()
+                .find(|w| **w == query)
+                .map(|w| DictionaryEntry {
+                    word: w.to_string(),
+                    phonetic: None,
+                    translation: Some("译文".to_string()),
+                    definition: None,
+                    pos: None,
+                    exchange: None,
+                    tag: None,
+                    examples: Vec::new(),
+                    source: DataSource::Local(self.name().to_string()),
+                }))
+        }
+
+        fn fuzzy_search(&self, query: &str, limit: usize) -> Result<Vec<String>> {
+            Ok(self
+                .0
+                .iter()
+                .filter(|w| w.starts_with(&query[..1]))
+                .take(limit)
+                .map(|w| w.to_string())
+                .collect())
+        }
+
+        fn is_available(&self) -> bool {
+            true
+        }
+
+        fn name(&self) -> &str {
+            "words"
+        }
+    }
+
+    impl LocalDictionary for WordList {}
+
+    #[test]
+    fn service_accepts_custom_backends() {
+        let service = DictionaryService::new(vec![Box::new(WordList(&["hello", "world"]))], None);
+        let options = LookupOptions::default();
+
+        match service.lookup("Hello", &options).unwrap() {
+            LookupResult::Found(entry) => assert_eq!(entry.word, "hello"),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert!(service.contains("world").unwrap());
+        match service.lookup("wrold", &options).unwrap() {
+            LookupResult::Suggestions(words) => assert_eq!(words, ["world"]),
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+
+    fn has_base(word: &str, base: &str) -> bool {
+        inflection_bases(word).iter().any(|b| b == base)
+    }
+
+    #[test]
+    fn inflection_bases_strip_regular_suffixes() {
+        assert!(has_base("cats", "cat"));
+        assert!(has_base("boxes", "box"));
+        assert!(has_base("walked", "walk"));
+        assert!(has_base("liked", "like"));
+        assert!(has_base("making", "make"));
+        assert!(has_base("faster", "fast"));
+        assert!(has_base("widest", "wide"));
+    }
+
+    #[test]
+    fn inflection_bases_handle_y_and_f_stems() {
+        assert!(has_base("studies", "study"));
+        assert!(has_base("carried", "carry"));
+        assert!(has_base("happiest", "happy"));
+        assert!(has_base("wolves", "wolf"));
+        assert!(has_base("knives", "knife"));
+    }
+
+    #[test]
+    fn inflection_bases_undouble_final_consonant() {
+        assert!(has_base("stopped", "stop"));
+        assert!(has_base("running", "run"));
+        assert!(has_base("bigger", "big"));
+        // 元音不做去重
+        assert!(!has_base("seeing", "se"));
+    }
+
+    #[test]
+    fn inflection_bases_handle_non_ascii_words() {
+        // 曾因按字节切分多字节字符而 panic
+        assert_eq!(inflection_bases("h₂s"), ["h₂"]);
+        assert!(has_base("cafés", "café"));
+        assert!(has_base("naïveest", "naïve"));
+        assert!(has_base("ü₂₂ed", "ü₂₂"));
+        assert!(!has_base("ü₂₂ed", "ü₂"));
+        assert!(has_base("grüssed", "grüs"));
+    }
+
+    #[test]
+    fn inflection_bases_skip_too_short_stems() {
+        assert!(inflection_bases("is").is_empty());
+        assert!(!has_base("red", "r"));
+        assert!(inflection_bases("cat").is_empty());
     }
 }
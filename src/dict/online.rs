@@ -1,12 +1,61 @@
 use anyhow::Result;
 use serde::Deserialize;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use super::Dictionary;
+use crate::tr;
 use crate::types::{DataSource, DictionaryEntry, Example};
 
+/// Free Dictionary API 地址
+const API_BASE: &str = "https://api.dictionaryapi.dev/api/v2/entries/en";
+
+/// 重试后仍被限流且没有 `Retry-After` 时的冷却时间
+const DEFAULT_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// 重试策略：指数退避
+#[derive(Debug, Clone)]
+struct RetryPolicy {
+    /// 最大重试次数（不含首次请求）
+    max_retries: u32,
+    /// 首次重试前的等待时间，之后每次翻倍
+    base_delay: Duration,
+    /// 单次等待上限；`Retry-After` 超过此值时不再重试，直接进入冷却
+    max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// 第 attempt 次重试（从 0 开始）前的等待时间
+    fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 1u32.checked_shl(attempt).unwrap_or(u32::MAX);
+        self.base_delay
+            .checked_mul(factor)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay)
+    }
+}
+
 /// Free Dictionary API 在线词典
 pub struct OnlineDictionary {
     client: reqwest::blocking::Client,
+    retry: RetryPolicy,
+    /// 剩余请求额度（含重试），None 表示不限
+    budget: Option<AtomicUsize>,
+    /// 记录限流冷却截止时间（Unix 秒）的文件，跨进程生效
+    cooldown_file: Option<PathBuf>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -20,6 +69,8 @@ struct ApiResponse {
 #[derive(Deserialize, Debug)]
 struct ApiPhonetic {
     text: Option<String>,
+    /// 发音音频地址，部分条目为空字符串
+    audio: Option<String>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -35,6 +86,12 @@ struct ApiDefinition {
     example: Option<String>,
 }
 
+impl Default for OnlineDictionary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl OnlineDictionary {
     pub fn new() -> Self {
         let client = reqwest::blocking::Client::builder()
@@ -42,7 +99,161 @@ impl OnlineDictionary {
             .user_agent("lango-cli/0.1")
             .build()
             .unwrap_or_default();
-        Self { client }
+        Self {
+            client,
+            retry: RetryPolicy::default(),
+            budget: None,
+            cooldown_file: None,
+        }
+    }
+
+    /// 设置本进程内的在线请求总额度，批量查询时避免触发 API 限流
+    pub fn with_request_budget(mut self, budget: usize) -> Self {
+        self.budget = Some(AtomicUsize::new(budget));
+        self
+    }
+
+    /// 将 HTTP 429 后的冷却时间记录到文件中，之后的进程在冷却结束前不再发出请求
+    ///
+    /// 逐词调用 `lango` 的脚本因此不会在被限流后继续请求。
+    pub fn with_cooldown_file(mut self, path: PathBuf) -> Self {
+        self.cooldown_file = Some(path);
+        self
+    }
+
+    /// 剩余的冷却时间，未在冷却中时为 None
+    fn cooldown_remaining(&self) -> Option<Duration> {
+        let path = self.cooldown_file.as_ref()?;
+        let until: u64 = fs::read_to_string(path).ok()?.trim().parse().ok()?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).ok()?.as_secs();
+        until
+            .checked_sub(now)
+            .filter(|s| *s > 0)
+            .map(Duration::from_secs)
+    }
+
+    /// 开始冷却，写入失败时忽略（只影响之后的进程）
+    fn start_cooldown(&self, wait: Duration) {
+        let Some(ref path) = self.cooldown_file else {
+            return;
+        };
+        let Ok(now) = SystemTime::now().duration_since(UNIX_EPOCH) else {
+            return;
+        };
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let _ = fs::write(path, (now + wait).as_secs().to_string());
+    }
+
+    /// 探测在线接口是否可达，返回响应状态与耗时（不重试、不计入额度）
+    pub fn probe(&self) -> Result<(reqwest::StatusCode, Duration)> {
+        let start = std::time::Instant::now();
+        let resp = self.client.get(format!("{}/hello", API_BASE)).send()?;
+        Ok((resp.status(), start.elapsed()))
+    }
+
+    /// 消耗一次请求额度，额度用尽时返回 false
+    fn take_budget(&self) -> bool {
+        match self.budget {
+            Some(ref remaining) => remaining
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok(),
+            None => true,
+        }
+    }
+
+    /// 带重试的 GET 请求
+    ///
+    /// 超时、连接错误、5xx 与 429 视为暂时性失败，按指数退避重试；
+    /// 429 响应若带 `Retry-After` 则以其为准，超过单次等待上限时直接放弃并进入冷却。
+    /// 返回 Ok(None) 表示网络不可用。
+    fn get_with_retry(&self, url: &str) -> Result<Option<reqwest::blocking::Response>> {
+        if let Some(remaining) = self.cooldown_remaining() {
+            anyhow::bail!(tr!("online.cooling_down", remaining.as_secs()));
+        }
+
+        let mut attempt = 0;
+        loop {
+            if !self.take_budget() {
+                anyhow::bail!(tr!("online.budget_exhausted"));
+            }
+
+            let (delay, rate_limited) = match self.client.get(url).send() {
+                Ok(resp) => {
+                    let status = resp.status();
+                    if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                        match retry_after(resp.headers()) {
+                            Some(wait) if wait > self.retry.max_delay => {
+                                self.start_cooldown(wait);
+                                anyhow::bail!(tr!("online.rate_limited"));
+                            }
+                            Some(wait) => (wait, true),
+                            None => (self.retry.backoff(attempt), true),
+                        }
+                    } else if status.is_server_error() {
+                        (self.retry.backoff(attempt), false)
+                    } else {
+                        return Ok(Some(resp));
+                    }
+                }
+                Err(e) if e.is_timeout() || e.is_connect() => (self.retry.backoff(attempt), false),
+                Err(_) => return Ok(None),
+            };
+
+            if attempt >= self.retry.max_retries {
+                if rate_limited {
+                    self.start_cooldown(DEFAULT_COOLDOWN);
+                    anyhow::bail!(tr!("online.rate_limited"));
+                }
+                return Ok(None); // 网络错误静默失败
+            }
+
+            thread::sleep(delay);
+            attempt += 1;
+        }
+    }
+
+    /// 查询单词的发音音频地址（美式发音在前），没有音频或网络不可用时返回空列表
+    pub fn audio_urls(&self, word: &str) -> Result<Vec<String>> {
+        let resp = match self.get_with_retry(entry_url(word).as_str())? {
+            Some(r) if r.status().is_success() => r,
+            _ => return Ok(Vec::new()),
+        };
+        let data: Vec<ApiResponse> = match resp.json() {
+            Ok(d) => d,
+            Err(_) => return Ok(Vec::new()),
+        };
+
+        let mut urls: Vec<String> = Vec::new();
+        for phonetic in data
+            .into_iter()
+            .flat_map(|r| r.phonetics.unwrap_or_default())
+        {
+            let Some(audio) = phonetic.audio.filter(|a| !a.is_empty()) else {
+                continue;
+            };
+            // 早期条目使用协议相对地址（`//ssl.gstatic.com/...`）
+            let audio = if audio.starts_with("//") {
+                format!("https:{}", audio)
+            } else {
+                audio
+            };
+            if !urls.contains(&audio) {
+                urls.push(audio);
+            }
+        }
+        urls.sort_by_key(|u| !u.ends_with("-us.mp3"));
+        Ok(urls)
+    }
+
+    /// 下载发音音频，与查询共用请求额度和重试策略
+    pub fn download_audio(&self, url: &str) -> Result<Vec<u8>> {
+        let resp = match self.get_with_retry(url)? {
+            Some(r) if r.status().is_success() => r,
+            _ => anyhow::bail!(tr!("pronounce.download_failed", url)),
+        };
+        Ok(resp.bytes()?.to_vec())
     }
 
     fn parse_response(&self, resp: Vec<ApiResponse>) -> Option<DictionaryEntry> {
@@ -111,13 +322,27 @@ impl OnlineDictionary {
     }
 }
 
+/// 单词的查询地址，单词作为一个路径段编码（空格、`/`、`?` 等不会破坏地址）
+fn entry_url(word: &str) -> reqwest::Url {
+    let mut url = reqwest::Url::parse(API_BASE).expect("API_BASE 是合法地址");
+    url.path_segments_mut()
+        .expect("https 地址可以追加路径")
+        .push(word);
+    url
+}
+
+/// 解析 `Retry-After` 头（仅支持秒数形式）
+fn retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    let value = headers.get(reqwest::header::RETRY_AFTER)?;
+    let secs: u64 = value.to_str().ok()?.trim().parse().ok()?;
+    Some(Duration::from_secs(secs))
+}
+
 impl Dictionary for OnlineDictionary {
     fn lookup(&self, query: &str) -> Result<Option<DictionaryEntry>> {
-        let url = format!("https://api.dictionaryapi.dev/api/v2/entries/en/{}", query);
-
-        let resp = match self.client.get(&url).send() {
-            Ok(r) => r,
-            Err(_) => return Ok(None), // 网络错误静默失败
+        let resp = match self.get_with_retry(entry_url(query).as_str())? {
+            Some(r) => r,
+            None => return Ok(None),
         };
 
         if !resp.status().is_success() {
@@ -145,3 +370,74 @@ impl Dictionary for OnlineDictionary {
         "Free Dictionary API"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::{HeaderMap, HeaderValue, RETRY_AFTER};
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.backoff(0), Duration::from_millis(500));
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(2), Duration::from_secs(2));
+        assert_eq!(policy.backoff(5), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn backoff_does_not_overflow() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.backoff(31), policy.max_delay);
+        assert_eq!(policy.backoff(64), policy.max_delay);
+        assert_eq!(policy.backoff(u32::MAX), policy.max_delay);
+    }
+
+    #[test]
+    fn cooldown_persists_in_file() {
+        let path = std::env::temp_dir().join(format!("lango-cooldown-{}", std::process::id()));
+        let online = OnlineDictionary::new().with_cooldown_file(path.clone());
+        assert_eq!(online.cooldown_remaining(), None);
+
+        online.start_cooldown(Duration::from_secs(30));
+        // 新的实例（如下一次运行）同样处于冷却中
+        let next = OnlineDictionary::new().with_cooldown_file(path.clone());
+        let remaining = next.cooldown_remaining().unwrap();
+        assert!(remaining <= Duration::from_secs(30) && remaining >= Duration::from_secs(28));
+        assert!(next.get_with_retry(API_BASE).is_err());
+
+        // 已过期的截止时间不再生效
+        fs::write(&path, "1").unwrap();
+        assert_eq!(next.cooldown_remaining(), None);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn entry_url_encodes_the_word() {
+        assert_eq!(entry_url("cat").as_str(), format!("{}/cat", API_BASE));
+        assert_eq!(
+            entry_url("take off").as_str(),
+            format!("{}/take%20off", API_BASE)
+        );
+        assert_eq!(
+            entry_url("a/b?c#d").as_str(),
+            format!("{}/a%2Fb%3Fc%23d", API_BASE)
+        );
+    }
+
+    #[test]
+    fn retry_after_parses_seconds() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static(" 7 "));
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(7)));
+
+        // HTTP 日期形式不支持，回退到指数退避
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(retry_after(&headers), None);
+    }
+}
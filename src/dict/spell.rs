@@ -0,0 +1,238 @@
+/// 拼写建议候选
+#[derive(Debug, Clone)]
+pub struct Suggestion {
+    pub word: String,
+    /// 与查询词的加权编辑距离
+    pub distance: f32,
+    /// ECDICT 词频排名（1 为最常用）
+    pub rank: Option<i64>,
+}
+
+impl Suggestion {
+    pub fn new(query: &str, word: String, rank: Option<i64>) -> Self {
+        let distance = weighted_distance(query, &word);
+        Self {
+            word,
+            distance,
+            rank,
+        }
+    }
+
+    /// 综合得分，越低越好
+    pub fn score(&self) -> f32 {
+        self.distance - frequency_bonus(self.rank)
+    }
+}
+
+/// 计算 American Soundex 键（如 "receive" -> "R210"）
+///
+/// 仅处理 ASCII 字母，其他字符被忽略；不含字母时返回 None。
+pub fn soundex(word: &str) -> Option<String> {
+    let mut letters = word
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .map(|c| c.to_ascii_lowercase());
+
+    let first = letters.next()?;
+    let mut key = String::with_capacity(4);
+    key.push(first.to_ascii_uppercase());
+
+    let mut last = soundex_digit(first);
+    for c in letters {
+        let digit = soundex_digit(c);
+        match digit {
+            // h / w 不分隔相同编码的辅音
+            None if c == 'h' || c == 'w' => continue,
+            None => last = None,
+            Some(d) => {
+                if last != Some(d) {
+                    key.push(d);
+                    if key.len() == 4 {
+                        break;
+                    }
+                }
+                last = Some(d);
+            }
+        }
+    }
+
+    while key.len() < 4 {
+        key.push('0');
+    }
+    Some(key)
+}
+
+/// Soundex 键去掉首字母后的数字部分（如 "P500" -> "500"）
+///
+/// Soundex 保留首字母，首字母拼错（fone / phone、kat / cat）时完整键不同，
+/// 用数字部分匹配可找回这类读音相近的候选。
+pub fn soundex_tail(key: &str) -> &str {
+    key.get(1..).unwrap_or("")
+}
+
+fn soundex_digit(c: char) -> Option<char> {
+    match c {
+        'b' | 'f' | 'p' | 'v' => Some('1'),
+        'c' | 'g' | 'j' | 'k' | 'q' | 's' | 'x' | 'z' => Some('2'),
+        'd' | 't' => Some('3'),
+        'l' => Some('4'),
+        'm' | 'n' => Some('5'),
+        'r' => Some('6'),
+        _ => None,
+    }
+}
+
+/// QWERTY 键盘布局，每行附带相对左侧的错位
+const KEYBOARD_ROWS: [(&str, f32); 3] =
+    [("qwertyuiop", 0.0), ("asdfghjkl", 0.25), ("zxcvbnm", 0.75)];
+
+fn key_position(c: char) -> Option<(f32, f32)> {
+    KEYBOARD_ROWS
+        .iter()
+        .enumerate()
+        .find_map(|(row, (keys, offset))| keys.find(c).map(|col| (row as f32, col as f32 + offset)))
+}
+
+/// 两个字母在键盘上是否相邻
+fn keyboard_adjacent(a: char, b: char) -> bool {
+    match (key_position(a), key_position(b)) {
+        (Some((ra, ca)), Some((rb, cb))) => {
+            a != b && (ra - rb).abs() <= 1.0 && (ca - cb).abs() <= 1.0
+        }
+        _ => false,
+    }
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y')
+}
+
+/// 替换代价：键盘相邻的误触与元音混淆比任意替换更可能
+fn substitution_cost(a: char, b: char) -> f32 {
+    if a == b {
+        0.0
+    } else if keyboard_adjacent(a, b) {
+        0.5
+    } else if is_vowel(a) && is_vowel(b) {
+        0.75
+    } else {
+        1.0
+    }
+}
+
+/// 加权编辑距离（Optimal String Alignment）
+///
+/// 插入/删除代价为 1，替换代价见 `substitution_cost`，相邻字母换位代价为 0.75。
+fn weighted_distance(a: &str, b: &str) -> f32 {
+    let a: Vec<char> = a.to_lowercase().chars().collect();
+    let b: Vec<char> = b.to_lowercase().chars().collect();
+    let (n, m) = (a.len(), b.len());
+
+    let mut d = vec![vec![0.0f32; m + 1]; n + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i as f32;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j as f32;
+    }
+
+    for i in 1..=n {
+        for j in 1..=m {
+            let mut cost = (d[i - 1][j] + 1.0)
+                .min(d[i][j - 1] + 1.0)
+                .min(d[i - 1][j - 1] + substitution_cost(a[i - 1], b[j - 1]));
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                cost = cost.min(d[i - 2][j - 2] + 0.75);
+            }
+            d[i][j] = cost;
+        }
+    }
+
+    d[n][m]
+}
+
+/// 词频加成：越常用加成越高，最高不超过 0.8，不足以抵消一次完整编辑
+///
+/// `rank` 为 ECDICT 的 frq/bnc 排名（1 为最常用），缺失时无加成。
+fn frequency_bonus(rank: Option<i64>) -> f32 {
+    match rank {
+        Some(r) if r > 0 => {
+            let scaled = 1.0 - (r as f32).ln() / 100_000f32.ln();
+            0.8 * scaled.clamp(0.0, 1.0)
+        }
+        _ => 0.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn soundex_matches_reference_codes() {
+        assert_eq!(soundex("Robert").as_deref(), Some("R163"));
+        assert_eq!(soundex("Rupert").as_deref(), Some("R163"));
+        assert_eq!(soundex("receive").as_deref(), Some("R210"));
+        assert_eq!(soundex("Tymczak").as_deref(), Some("T522"));
+        assert_eq!(soundex("Pfister").as_deref(), Some("P236"));
+        // h / w 不分隔相同编码的辅音
+        assert_eq!(soundex("Ashcraft").as_deref(), Some("A261"));
+        assert_eq!(soundex("a").as_deref(), Some("A000"));
+        assert_eq!(soundex("123"), None);
+    }
+
+    #[test]
+    fn soundex_tail_ignores_the_first_letter() {
+        let phone = soundex("phone").unwrap();
+        let fone = soundex("fone").unwrap();
+        assert_ne!(phone, fone);
+        assert_eq!(soundex_tail(&phone), soundex_tail(&fone));
+        assert_eq!(soundex_tail("C300"), "300");
+        assert_eq!(soundex_tail(""), "");
+    }
+
+    #[test]
+    fn weighted_distance_discounts_likely_typos() {
+        assert_eq!(weighted_distance("hello", "hello"), 0.0);
+        assert_eq!(weighted_distance("Hello", "hello"), 0.0);
+        // 键盘相邻误触
+        assert_eq!(weighted_distance("hwllo", "hello"), 0.5);
+        // 元音混淆
+        assert_eq!(weighted_distance("hallo", "hello"), 0.75);
+        // 相邻换位
+        assert_eq!(weighted_distance("recieve", "receive"), 0.75);
+        // 任意替换、插入与删除
+        assert_eq!(weighted_distance("hxllo", "hello"), 1.0);
+        assert_eq!(weighted_distance("helo", "hello"), 1.0);
+        assert_eq!(weighted_distance("", "abc"), 3.0);
+    }
+
+    #[test]
+    fn keyboard_adjacency_follows_qwerty_rows() {
+        assert!(keyboard_adjacent('e', 'w'));
+        assert!(keyboard_adjacent('e', 'd'));
+        assert!(keyboard_adjacent('g', 'b'));
+        assert!(!keyboard_adjacent('q', 'p'));
+        assert!(!keyboard_adjacent('a', 'a'));
+    }
+
+    #[test]
+    fn frequency_bonus_favours_common_words_within_one_edit() {
+        assert_eq!(frequency_bonus(None), 0.0);
+        assert_eq!(frequency_bonus(Some(0)), 0.0);
+        assert!(frequency_bonus(Some(1)) <= 0.8);
+        assert!(frequency_bonus(Some(100)) > frequency_bonus(Some(10_000)));
+        assert_eq!(frequency_bonus(Some(1_000_000)), 0.0);
+    }
+
+    #[test]
+    fn suggestion_score_prefers_closer_then_more_common_words() {
+        let near = Suggestion::new("recieve", "receive".to_string(), Some(2000));
+        let far = Suggestion::new("recieve", "believe".to_string(), Some(10));
+        assert!(near.score() < far.score());
+
+        let common = Suggestion::new("cot", "cat".to_string(), Some(100));
+        let rare = Suggestion::new("cot", "cut".to_string(), Some(50_000));
+        assert!(common.score() < rare.score());
+    }
+}
@@ -0,0 +1,221 @@
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+use lango::dict::LocalDictionary;
+use lango::dict::ecdict::EcdictDictionary;
+use lango::tr;
+
+use crate::setup;
+
+/// 默认词库名称，对应 `setup::db_path()`
+pub use lango::dict::DEFAULT_DICT;
+
+/// 已安装的词库
+#[derive(Debug, Clone)]
+pub struct InstalledDict {
+    pub name: String,
+    pub path: PathBuf,
+    pub enabled: bool,
+}
+
+/// 词库启用状态，保存在 `dicts.json`
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct DictsState {
+    #[serde(default)]
+    disabled: Vec<String>,
+}
+
+/// 获取附加词库存储目录
+pub fn dicts_dir() -> Result<PathBuf> {
+    Ok(setup::data_dir()?.join("dicts"))
+}
+
+/// 获取指定名称的词库文件路径
+pub fn dict_path(name: &str) -> Result<PathBuf> {
+    validate_name(name)?;
+    if name == DEFAULT_DICT {
+        setup::db_path()
+    } else {
+        Ok(dicts_dir()?.join(format!("{}.db", name)))
+    }
+}
+
+/// 词库名仅允许字母、数字、`-` 与 `_`
+fn validate_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        anyhow::bail!(tr!("dicts.invalid_name", name));
+    }
+    Ok(())
+}
+
+fn state_path() -> Result<PathBuf> {
+    Ok(setup::data_dir()?.join("dicts.json"))
+}
+
+fn read_state() -> DictsState {
+    state_path()
+        .ok()
+        .and_then(|p| fs::read_to_string(p).ok())
+        .and_then(|data| serde_json::from_str(&data).ok())
+        .unwrap_or_default()
+}
+
+fn write_state(state: &DictsState) -> Result<()> {
+    let path = state_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, serde_json::to_string_pretty(state)?)
+        .with_context(|| tr!("dicts.write_state_failed", path.display()))
+}
+
+/// 列出已安装的词库，默认词库在前，其余按名称排序
+pub fn list_installed() -> Result<Vec<InstalledDict>> {
+    let state = read_state();
+    let enabled = |name: &str| !state.disabled.iter().any(|d| d == name);
+
+    let mut dicts = Vec::new();
+    let default_path = setup::db_path()?;
+    if default_path.exists() {
+        dicts.push(InstalledDict {
+            name: DEFAULT_DICT.to_string(),
+            path: default_path,
+            enabled: enabled(DEFAULT_DICT),
+        });
+    }
+
+    let mut extra = Vec::new();
+    if let Ok(entries) = fs::read_dir(dicts_dir()?) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("db") {
+                continue;
+            }
+            let Some(name) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            extra.push(InstalledDict {
+                name: name.to_string(),
+                enabled: enabled(name),
+                path,
+            });
+        }
+    }
+    extra.sort_by(|a, b| a.name.cmp(&b.name));
+    dicts.extend(extra);
+
+    Ok(dicts)
+}
+
+/// 是否安装了任意词库
+pub fn any_installed() -> bool {
+    list_installed().map(|d| !d.is_empty()).unwrap_or(false)
+}
+
+/// 按名称查找已安装的词库
+fn find(name: &str) -> Result<InstalledDict> {
+    list_installed()?
+        .into_iter()
+        .find(|d| d.name == name)
+        .with_context(|| tr!("dicts.not_installed", name))
+}
+
+/// 打开要查询的词库
+///
+/// `selection` 为空时打开所有已启用的词库；否则按给定顺序打开，
+/// 显式指定的词库即使被禁用也会查询。
+pub fn open_selected(selection: &[String]) -> Result<Vec<Box<dyn LocalDictionary>>> {
+    let dicts = if selection.is_empty() {
+        list_installed()?
+            .into_iter()
+            .filter(|d| d.enabled)
+            .collect()
+    } else {
+        selection
+            .iter()
+            .map(|name| find(name))
+            .collect::<Result<Vec<_>>>()?
+    };
+
+    dicts
+        .into_iter()
+        .map(|d| {
+            let dict = EcdictDictionary::open(&d.path)?.with_name(&d.name);
+            Ok(Box::new(dict) as Box<dyn LocalDictionary>)
+        })
+        .collect()
+}
+
+/// 启用或禁用词库
+pub fn set_enabled(name: &str, enabled: bool) -> Result<()> {
+    find(name)?;
+    let mut state = read_state();
+    state.disabled.retain(|d| d != name);
+    if !enabled {
+        state.disabled.push(name.to_string());
+    }
+    write_state(&state)?;
+
+    if enabled {
+        println!("  {}", tr!("dicts.enabled", name));
+    } else {
+        println!("  {}", tr!("dicts.disabled", name));
+    }
+    Ok(())
+}
+
+/// 删除词库及其元数据
+pub fn remove(name: &str) -> Result<()> {
+    let dict = find(name)?;
+    fs::remove_file(&dict.path).with_context(|| tr!("dicts.remove_failed", dict.path.display()))?;
+    let _ = fs::remove_file(setup::meta_path(&dict.path));
+
+    let mut state = read_state();
+    if state.disabled.iter().any(|d| d == name) {
+        state.disabled.retain(|d| d != name);
+        write_state(&state)?;
+    }
+
+    println!("  {}", tr!("dicts.removed", name));
+    Ok(())
+}
+
+/// 输出已安装词库列表
+pub fn print_list() -> Result<()> {
+    let dicts = list_installed()?;
+    println!();
+    if dicts.is_empty() {
+        println!("  {}", tr!("dicts.none").dimmed());
+        println!();
+        return Ok(());
+    }
+
+    for dict in &dicts {
+        let mark = if dict.enabled {
+            "●".green()
+        } else {
+            "○".dimmed()
+        };
+        let version = setup::read_meta(&dict.path)
+            .and_then(|m| m.version)
+            .unwrap_or_else(|| "-".to_string());
+        let size = fs::metadata(&dict.path).map(|m| m.len()).unwrap_or(0);
+        println!(
+            "  {} {:<16} {:<10} {:>10}  {}",
+            mark,
+            dict.name.bold(),
+            version,
+            indicatif::HumanBytes(size).to_string(),
+            dict.path.display().to_string().dimmed()
+        );
+    }
+    println!();
+    Ok(())
+}
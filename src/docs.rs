@@ -0,0 +1,153 @@
+use anyhow::{Context, Result};
+use clap::{Arg, Command};
+use std::fmt::Write as _;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+use lango::tr;
+
+use crate::cli::{self, DocFormat};
+
+/// 生成文档；指定 `out_dir` 时写入文件，否则输出到标准输出
+pub fn generate(format: DocFormat, out_dir: Option<&Path>) -> Result<()> {
+    // 不生成 help 子命令的页面
+    let mut cmd = cli::command().disable_help_subcommand(true);
+    cmd.build();
+
+    match (format, out_dir) {
+        (DocFormat::Man, Some(dir)) => {
+            fs::create_dir_all(dir)?;
+            // 为主命令及每个子命令生成单独的页面（lango.1、lango-setup.1 ...）
+            clap_mangen::generate_to(cmd, dir)
+                .with_context(|| tr!("docs.man_write_failed", dir.display()))?;
+            println!("  {}", tr!("docs.man_generated", dir.display()));
+        }
+        (DocFormat::Man, None) => {
+            clap_mangen::Man::new(cmd).render(&mut io::stdout().lock())?;
+        }
+        (DocFormat::Markdown, out_dir) => {
+            let mut md = String::new();
+            write_command(&mut md, &cmd, 1);
+            match out_dir {
+                Some(dir) => {
+                    fs::create_dir_all(dir)?;
+                    let path = dir.join(format!("{}.md", cmd.get_name()));
+                    fs::write(&path, md)
+                        .with_context(|| tr!("docs.write_failed", path.display()))?;
+                    println!("  {}", tr!("docs.generated", path.display()));
+                }
+                None => io::stdout().lock().write_all(md.as_bytes())?,
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// 递归输出命令及其子命令的 Markdown 参考
+fn write_command(md: &mut String, cmd: &Command, depth: usize) {
+    let name = cmd.get_bin_name().unwrap_or(cmd.get_name());
+    let _ = writeln!(md, "{} `{}`\n", "#".repeat(depth), name);
+
+    if let Some(about) = cmd.get_long_about().or(cmd.get_about()) {
+        let _ = writeln!(md, "{}\n", about);
+    }
+
+    let usage = cmd.clone().render_usage().to_string();
+    let usage = usage.trim_start_matches("Usage:").trim();
+    let _ = writeln!(md, "```text\n{}\n```\n", usage);
+
+    // 全局选项只在主命令中列出
+    let args: Vec<&Arg> = cmd
+        .get_arguments()
+        .filter(|a| !a.is_hide_set())
+        .filter(|a| depth == 1 || !a.is_global_set())
+        .collect();
+
+    let positionals: Vec<&&Arg> = args.iter().filter(|a| a.is_positional()).collect();
+    if !positionals.is_empty() {
+        let _ = writeln!(md, "**{}**\n", tr!("docs.arguments"));
+        for arg in positionals {
+            let value = arg
+                .get_value_names()
+                .and_then(|v| v.first())
+                .map(|v| v.to_string())
+                .unwrap_or_else(|| arg.get_id().to_string().to_uppercase());
+            let _ = writeln!(md, "- `<{}>`: {}", value, arg_help(arg));
+        }
+        md.push('\n');
+    }
+
+    let options: Vec<&&Arg> = args.iter().filter(|a| !a.is_positional()).collect();
+    if !options.is_empty() {
+        let _ = writeln!(md, "**{}**\n", tr!("docs.options"));
+        for arg in options {
+            let _ = writeln!(md, "- `{}`: {}", option_flags(arg), arg_help(arg));
+        }
+        md.push('\n');
+    }
+
+    if let Some(after) = cmd.get_after_long_help().or(cmd.get_after_help()) {
+        let _ = writeln!(md, "```text\n{}\n```\n", after.to_string().trim_end());
+    }
+
+    for sub in cmd.get_subcommands() {
+        if sub.is_hide_set() {
+            continue;
+        }
+        write_command(md, sub, depth + 1);
+    }
+}
+
+/// 形如 `-n, --num-examples <N>` 的选项写法
+fn option_flags(arg: &Arg) -> String {
+    let mut flags = Vec::new();
+    if let Some(short) = arg.get_short() {
+        flags.push(format!("-{}", short));
+    }
+    if let Some(long) = arg.get_long() {
+        flags.push(format!("--{}", long));
+    }
+    let mut s = flags.join(", ");
+    if arg.get_action().takes_values() {
+        let value = arg
+            .get_value_names()
+            .and_then(|v| v.first())
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| arg.get_id().to_string().to_uppercase());
+        let _ = write!(s, " <{}>", value);
+    }
+    s
+}
+
+/// 选项说明，附带默认值与可选值
+fn arg_help(arg: &Arg) -> String {
+    let mut help = arg
+        .get_long_help()
+        .or(arg.get_help())
+        .map(|h| h.to_string())
+        .unwrap_or_default();
+
+    let possible: Vec<String> = arg
+        .get_possible_values()
+        .iter()
+        .filter(|v| !v.is_hide_set())
+        .map(|v| format!("`{}`", v.get_name()))
+        .collect();
+    if !possible.is_empty() {
+        help.push_str(&tr!("docs.possible_values", possible.join(", ")));
+    }
+
+    let defaults: Vec<String> = arg
+        .get_default_values()
+        .iter()
+        .map(|v| v.to_string_lossy().into_owned())
+        .filter(|v| !v.is_empty())
+        .collect();
+    if !defaults.is_empty() && arg.get_action().takes_values() {
+        help.push_str(&tr!("docs.default", defaults.join(",")));
+    }
+
+    help
+}
@@ -0,0 +1,212 @@
+use anyhow::Result;
+use colored::Colorize;
+use rusqlite::{Connection, OpenFlags};
+use std::fs;
+use std::path::Path;
+
+use lango::dict::ecdict::EcdictDictionary;
+use lango::dict::online::OnlineDictionary;
+use lango::tr;
+
+use crate::dicts;
+use crate::report::{item, section};
+use crate::setup;
+
+/// 输出诊断信息，便于粘贴到问题报告中；`fix` 为真时先补建缺失的拼写索引
+pub fn run(fix: bool) -> Result<()> {
+    if fix {
+        fix_spell_indexes()?;
+    }
+    println!();
+    section("Lango");
+    item(tr!("doctor.version"), env!("CARGO_PKG_VERSION"));
+    item(
+        tr!("doctor.system"),
+        &format!("{} {}", std::env::consts::OS, std::env::consts::ARCH),
+    );
+    println!();
+
+    section(tr!("doctor.dictionary"));
+    let data_dir = setup::data_dir()?;
+    item(tr!("doctor.data_dir"), &data_dir.display().to_string());
+    let db_path = setup::db_path()?;
+    item(tr!("doctor.db_path"), &db_path.display().to_string());
+    match fs::metadata(&db_path) {
+        Ok(meta) => {
+            item(
+                tr!("doctor.file_size"),
+                &indicatif::HumanBytes(meta.len()).to_string(),
+            );
+            print_db_stats(&db_path);
+        }
+        Err(_) => item(
+            tr!("doctor.file_size"),
+            &tr!("doctor.not_installed").red().to_string(),
+        ),
+    }
+    match setup::read_meta(&db_path) {
+        Some(meta) => {
+            item(
+                tr!("doctor.ecdict_version"),
+                meta.version.as_deref().unwrap_or(tr!("doctor.imported")),
+            );
+            item(tr!("doctor.source"), &meta.source);
+        }
+        None => item(tr!("doctor.ecdict_version"), tr!("common.unknown")),
+    }
+    let installed = dicts::list_installed()?;
+    if installed.len() > 1 {
+        let names: Vec<String> = installed
+            .iter()
+            .map(|d| {
+                if d.enabled {
+                    d.name.clone()
+                } else {
+                    tr!("doctor.disabled", d.name)
+                }
+            })
+            .collect();
+        item(tr!("doctor.installed"), &names.join(", "));
+    }
+    println!();
+
+    section(tr!("doctor.config_cache"));
+    let config_path = setup::config_path()?;
+    item(tr!("doctor.config_file"), &with_existence(&config_path));
+    let cache_dir = setup::cache_dir()?;
+    item(tr!("doctor.cache_dir"), &with_existence(&cache_dir));
+    if cache_dir.exists() {
+        let (files, bytes) = dir_stats(&cache_dir);
+        item(
+            tr!("doctor.cache_usage"),
+            &tr!("doctor.cache_files", files, indicatif::HumanBytes(bytes)),
+        );
+    }
+    println!();
+
+    section(tr!("doctor.online"));
+    match OnlineDictionary::new().probe() {
+        Ok((status, elapsed)) => item(
+            tr!("doctor.reachability"),
+            &format!(
+                "HTTP {} · {:.0}ms",
+                status.as_u16(),
+                elapsed.as_secs_f64() * 1000.0
+            ),
+        ),
+        Err(e) => item(
+            tr!("doctor.reachability"),
+            &format!("{} ({})", tr!("doctor.unreachable").red(), e),
+        ),
+    }
+    println!();
+
+    Ok(())
+}
+
+/// 以只读方式打开词库，避免诊断本身修改 journal 模式
+fn print_db_stats(db_path: &Path) {
+    let conn = match Connection::open_with_flags(db_path, OpenFlags::SQLITE_OPEN_READ_ONLY) {
+        Ok(c) => c,
+        Err(e) => {
+            item(
+                tr!("doctor.status"),
+                &format!("{} ({})", tr!("doctor.open_failed").red(), e),
+            );
+            return;
+        }
+    };
+
+    match conn.query_row("SELECT COUNT(*) FROM stardict", [], |row| {
+        row.get::<_, i64>(0)
+    }) {
+        Ok(count) => item(tr!("doctor.entries"), &count.to_string()),
+        Err(e) => item(
+            tr!("doctor.entries"),
+            &format!("{} ({})", tr!("doctor.read_failed").red(), e),
+        ),
+    }
+
+    let indexes: Vec<String> = conn
+        .prepare("SELECT name FROM sqlite_master WHERE type = 'index' AND tbl_name = 'stardict'")
+        .and_then(|mut stmt| {
+            stmt.query_map([], |row| row.get(0))?
+                .collect::<rusqlite::Result<Vec<String>>>()
+        })
+        .unwrap_or_default();
+    if indexes.is_empty() {
+        item(
+            tr!("doctor.indexes"),
+            &tr!("doctor.no_indexes").yellow().to_string(),
+        );
+    } else {
+        item(tr!("doctor.indexes"), &indexes.join(", "));
+    }
+
+    let spell_index: bool = conn
+        .query_row(
+            "SELECT COUNT(*) > 0 FROM pragma_table_info('lango_spell') WHERE name = 'tail'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap_or(false);
+    item(
+        tr!("doctor.spell_index"),
+        if spell_index {
+            tr!("doctor.spell_built")
+        } else {
+            tr!("doctor.spell_missing")
+        },
+    );
+
+    if let Ok(mode) = conn.query_row("PRAGMA journal_mode", [], |row| row.get::<_, String>(0)) {
+        item(tr!("doctor.journal_mode"), &mode);
+    }
+}
+
+/// 为缺少（或格式过旧的）拼写索引的已安装词库建立索引
+fn fix_spell_indexes() -> Result<()> {
+    let mut fixed = false;
+    for dict in dicts::list_installed()? {
+        if EcdictDictionary::open(&dict.path)?.has_spell_index()? {
+            continue;
+        }
+        println!();
+        println!("  {}", tr!("doctor.fixing_spell_index", dict.name));
+        setup::build_spell_index(&dict.path)?;
+        fixed = true;
+    }
+    if !fixed {
+        println!();
+        println!("  {}", tr!("doctor.nothing_to_fix"));
+    }
+    Ok(())
+}
+
+fn with_existence(path: &Path) -> String {
+    if path.exists() {
+        path.display().to_string()
+    } else {
+        format!("{} {}", path.display(), tr!("doctor.missing").dimmed())
+    }
+}
+
+/// 统计目录下的文件数与总大小（递归）
+fn dir_stats(dir: &Path) -> (u64, u64) {
+    let mut files = 0;
+    let mut bytes = 0;
+    if let Ok(entries) = fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                let (f, b) = dir_stats(&path);
+                files += f;
+                bytes += b;
+            } else if let Ok(meta) = entry.metadata() {
+                files += 1;
+                bytes += meta.len();
+            }
+        }
+    }
+    (files, bytes)
+}
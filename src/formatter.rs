@@ -1,7 +1,8 @@
 use colored::Colorize;
 use std::time::Duration;
 
-use crate::types::{DictionaryEntry, LookupResult};
+use crate::tr;
+use crate::types::{DataSource, DictionaryEntry, LookupResult, SearchHit, SourceResult};
 
 /// 格式化并输出查询结果
 pub fn print_result(
@@ -13,11 +14,63 @@ pub fn print_result(
 ) {
     match result {
         LookupResult::Found(entry) => print_entry(entry, show_english, show_examples, elapsed),
+        LookupResult::Corrected(entry) => {
+            println!();
+            println!(
+                "  {} {}",
+                "→".cyan(),
+                tr!("lookup.corrected", query.yellow(), entry.word.bright_blue())
+            );
+            print_entry(entry, show_english, show_examples, elapsed);
+        }
         LookupResult::NotFound => print_not_found(query),
         LookupResult::Suggestions(suggestions) => print_suggestions(query, suggestions),
     }
 }
 
+/// 按来源分节输出所有后端的查询结果（`--all`）
+pub fn print_all(
+    results: &[SourceResult],
+    query: &str,
+    show_english: bool,
+    show_examples: bool,
+    elapsed: Duration,
+) {
+    println!();
+    for result in results {
+        let label = format!("━━ {} ━━", result.source);
+        println!("  {}", label.bright_magenta().bold());
+
+        match (&result.entry, &result.error) {
+            (Some(entry), _) => {
+                println!();
+                // 在线词典没有中文释义，总是显示英文定义以便对比
+                let show_english = show_english || result.source == DataSource::Online;
+                print_entry_body(entry, show_english, show_examples);
+            }
+            (None, Some(error)) => {
+                println!("    {} {}", "✗".red(), error.dimmed());
+                println!();
+            }
+            (None, None) => {
+                println!(
+                    "    {} {}",
+                    "✗".red(),
+                    tr!("lookup.not_found", query.yellow())
+                );
+                println!();
+            }
+        }
+    }
+
+    println!(
+        "  {} {}",
+        "──".dimmed(),
+        tr!("lookup.sources", results.len(), millis(elapsed)).dimmed()
+    );
+    println!();
+}
+
 fn print_entry(
     entry: &DictionaryEntry,
     show_english: bool,
@@ -25,7 +78,20 @@ fn print_entry(
     elapsed: Duration,
 ) {
     println!();
+    print_entry_body(entry, show_english, show_examples);
+
+    // 来源 + 耗时
+    println!(
+        "  {} {} {}",
+        "──".dimmed(),
+        entry.source.to_string().dimmed(),
+        format!("· {}ms", millis(elapsed)).dimmed()
+    );
+    println!();
+}
 
+/// 输出词条内容（不含来源行）
+fn print_entry_body(entry: &DictionaryEntry, show_english: bool, show_examples: bool) {
     // 单词 + 音标
     let word_display = format!("  {}", entry.word).bold().bright_blue();
     if let Some(ref phonetic) = entry.phonetic {
@@ -43,7 +109,7 @@ fn print_entry(
 
     // 中文释义
     if let Some(ref translation) = entry.translation {
-        println!("  {}", "中文释义".bright_white().underline());
+        println!("  {}", tr!("entry.translation").bright_white().underline());
         for line in translation.lines() {
             let line = line.trim();
             if !line.is_empty() {
@@ -56,7 +122,7 @@ fn print_entry(
     // 英文释义 (需 -e 标志)
     if show_english {
         if let Some(ref definition) = entry.definition {
-            println!("  {}", "英文定义".bright_white().underline());
+            println!("  {}", tr!("entry.definition").bright_white().underline());
             for (i, line) in definition.lines().enumerate() {
                 let line = line.trim();
                 if !line.is_empty() && i < 5 {
@@ -69,7 +135,7 @@ fn print_entry(
 
     // 例句 (需 -x 标志)
     if show_examples && !entry.examples.is_empty() {
-        println!("  {}", "例句".bright_white().underline());
+        println!("  {}", tr!("entry.examples").bright_white().underline());
         for (i, ex) in entry.examples.iter().enumerate() {
             println!("    {}. {}", i + 1, ex.english);
             if let Some(ref zh) = ex.chinese {
@@ -84,42 +150,138 @@ fn print_entry(
         if !exchange.is_empty() {
             let formatted = format_exchange(exchange);
             if !formatted.is_empty() {
-                println!("  {}", "词形变换".bright_white().underline());
+                println!("  {}", tr!("entry.exchange").bright_white().underline());
                 println!("    {}", formatted.dimmed());
                 println!();
             }
         }
     }
+}
 
-    // 来源 + 耗时
-    let ms = elapsed.as_micros() as f64 / 1000.0;
+/// 输出搜索结果列表（词头 + 首行中文释义）
+pub fn print_hits(title: &str, hits: &[SearchHit], elapsed: Duration) {
+    println!();
+    if hits.is_empty() {
+        print_no_match(title);
+        return;
+    }
+
+    print_hit_list(hits);
     println!(
-        "  {} {} {}",
+        "  {} {}",
         "──".dimmed(),
-        entry.source.to_string().dimmed(),
-        format!("· {:.1}ms", ms).dimmed()
+        tr!("hits.count", hits.len(), millis(elapsed)).dimmed()
+    );
+    println!();
+}
+
+/// 输出分页的搜索结果，`page` 从 1 开始
+pub fn print_hit_page(
+    title: &str,
+    hits: &[SearchHit],
+    page: usize,
+    page_size: usize,
+    total: usize,
+    elapsed: Duration,
+) {
+    println!();
+    let pages = total.div_ceil(page_size.max(1));
+    if hits.is_empty() {
+        if total > 0 {
+            println!(
+                "  {} {}",
+                "✗".red(),
+                tr!("hits.page_out_of_range", page, pages, total)
+            );
+            println!();
+        } else {
+            print_no_match(title);
+        }
+        return;
+    }
+
+    print_hit_list(hits);
+    println!(
+        "  {} {}",
+        "──".dimmed(),
+        tr!("hits.page", page, pages, total, millis(elapsed)).dimmed()
     );
+    if page < pages {
+        println!("  {}", tr!("hits.next_page", page + 1).dimmed());
+    }
     println!();
 }
 
+fn print_no_match(title: &str) {
+    println!("  {} {}", "✗".red(), tr!("hits.no_match", title.yellow()));
+    println!();
+}
+
+fn print_hit_list(hits: &[SearchHit]) {
+    let width = hits
+        .iter()
+        .map(|h| h.word.chars().count())
+        .max()
+        .unwrap_or(0);
+    for hit in hits {
+        let padding = " ".repeat(width - hit.word.chars().count());
+        let brief = hit
+            .translation
+            .as_deref()
+            .and_then(|t| t.lines().map(str::trim).find(|l| !l.is_empty()))
+            .unwrap_or("");
+        println!(
+            "  {}{}  {}",
+            hit.word.bright_blue().bold(),
+            padding,
+            truncate(brief, 40).green()
+        );
+    }
+    println!();
+}
+
+/// 耗时的毫秒数，保留一位小数
+fn millis(elapsed: Duration) -> String {
+    format!("{:.1}", elapsed.as_micros() as f64 / 1000.0)
+}
+
+/// 按字符数截断，超出部分以省略号表示
+pub fn truncate(s: &str, max: usize) -> String {
+    if s.chars().count() <= max {
+        s.to_string()
+    } else {
+        let mut out: String = s.chars().take(max).collect();
+        out.push('…');
+        out
+    }
+}
+
 fn print_not_found(query: &str) {
     println!();
-    println!("  {} 未找到 \"{}\"", "✗".red(), query.yellow());
+    println!(
+        "  {} {}",
+        "✗".red(),
+        tr!("lookup.not_found", query.yellow())
+    );
     println!();
-    println!("  {}", "提示: 使用 --online 尝试在线查询".dimmed());
+    println!("  {}", tr!("lookup.hint_online").dimmed());
     println!();
 }
 
 fn print_suggestions(query: &str, suggestions: &[String]) {
     println!();
-    println!("  {} 未找到 \"{}\"", "✗".red(), query.yellow());
+    println!(
+        "  {} {}",
+        "✗".red(),
+        tr!("lookup.not_found", query.yellow())
+    );
     println!();
-    println!("  {}", "你是不是要找:".bright_white());
+    println!("  {}", tr!("lookup.did_you_mean").bright_white());
     for s in suggestions {
         println!("    {} {}", "→".cyan(), s);
     }
     println!();
-    println!("  {}", "提示: 使用 --online 尝试在线查询".dimmed());
+    println!("  {}", tr!("lookup.hint_online").dimmed());
     println!();
 }
 
@@ -131,15 +293,15 @@ fn format_exchange(exchange: &str) -> String {
         let mut kv = item.splitn(2, ':');
         if let (Some(key), Some(value)) = (kv.next(), kv.next()) {
             let label = match key {
-                "p" => "过去式",
-                "d" => "过去分词",
-                "i" => "现在分词",
-                "3" => "第三人称",
-                "s" => "复数",
-                "r" => "比较级",
-                "t" => "最高级",
-                "0" => "原型",
-                "1" => "原型变换",
+                "p" => tr!("exchange.past"),
+                "d" => tr!("exchange.past_participle"),
+                "i" => tr!("exchange.present_participle"),
+                "3" => tr!("exchange.third_person"),
+                "s" => tr!("exchange.plural"),
+                "r" => tr!("exchange.comparative"),
+                "t" => tr!("exchange.superlative"),
+                "0" => tr!("exchange.lemma"),
+                "1" => tr!("exchange.lemma_form"),
                 _ => continue,
             };
             parts.push(format!("{}: {}", label, value));
@@ -0,0 +1,169 @@
+use anyhow::Result;
+use colored::Colorize;
+use std::collections::HashSet;
+use std::path::Path;
+
+use lango::dict::DictionaryService;
+use lango::tr;
+use lango::types::{EXAM_TAGS, WordInfo};
+
+use crate::cli::GlossFormat;
+use crate::text::{self, Lemma, Vocabulary};
+
+/// 生词筛选条件，各条件同时满足才列出
+pub struct Difficulty {
+    /// 熟词（小写原形），不列出
+    pub known: HashSet<String>,
+    /// 只列出词频排名在此之后的单词（无排名视为罕见）
+    pub min_rank: Option<i64>,
+    /// 只列出不属于更低级别考试的单词（[`EXAM_TAGS`] 中的位置，无标签视为超纲）
+    pub level: Option<usize>,
+}
+
+impl Difficulty {
+    /// `level` 为考试标签名，如 `cet6`
+    pub fn new(known: HashSet<String>, min_rank: Option<i64>, level: Option<&str>) -> Self {
+        Self {
+            known,
+            min_rank,
+            level: level.and_then(|t| EXAM_TAGS.iter().position(|e| *e == t)),
+        }
+    }
+
+    /// 是否为需要列出的生词
+    pub fn is_difficult(&self, info: &WordInfo) -> bool {
+        if self.known.contains(&info.word.to_lowercase()) {
+            return false;
+        }
+        if let Some(min_rank) = self.min_rank {
+            if info.rank().is_some_and(|r| r <= min_rank) {
+                return false;
+            }
+        }
+        if let Some(level) = self.level {
+            if info.level().is_some_and(|l| l < level) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// 为文本生成生词表
+pub fn run(
+    service: &DictionaryService,
+    path: &Path,
+    difficulty: &Difficulty,
+    format: GlossFormat,
+) -> Result<()> {
+    let input = text::read_input(path)?;
+    let vocabulary = Vocabulary::collect(service, &input)?;
+    let glossary: Vec<&Lemma> = vocabulary
+        .lemmas
+        .iter()
+        .filter(|l| difficulty.is_difficult(&l.info))
+        .collect();
+
+    match format {
+        GlossFormat::Text => print_text(&vocabulary, &glossary),
+        GlossFormat::Markdown => print_markdown(&glossary),
+        GlossFormat::Csv => print_csv(&glossary)?,
+    }
+    Ok(())
+}
+
+fn print_text(vocabulary: &Vocabulary, glossary: &[&Lemma]) {
+    println!();
+    let width = glossary
+        .iter()
+        .map(|l| l.info.word.chars().count())
+        .max()
+        .unwrap_or(0);
+    for lemma in glossary {
+        let info = &lemma.info;
+        let padding = " ".repeat(width - info.word.chars().count());
+        let phonetic = info
+            .phonetic
+            .as_deref()
+            .filter(|p| !p.is_empty())
+            .map(|p| format!("/{}/ ", p.trim_matches('/')))
+            .unwrap_or_default();
+        let count = if lemma.count > 1 {
+            format!(" ×{}", lemma.count)
+        } else {
+            String::new()
+        };
+        println!(
+            "  {}{}  {}{}{}",
+            info.word.bright_blue().bold(),
+            padding,
+            phonetic.cyan(),
+            text::brief_translation(info).green(),
+            count.dimmed()
+        );
+    }
+    if !glossary.is_empty() {
+        println!();
+    }
+    println!(
+        "  {} {}",
+        "──".dimmed(),
+        tr!(
+            "gloss.summary",
+            glossary.len(),
+            vocabulary.lemmas.len(),
+            vocabulary.tokens
+        )
+        .dimmed()
+    );
+    if !vocabulary.unknown.is_empty() {
+        println!(
+            "  {}",
+            tr!("gloss.unknown", vocabulary.unknown.join(", ")).dimmed()
+        );
+    }
+    println!();
+}
+
+fn print_markdown(glossary: &[&Lemma]) {
+    println!(
+        "| {} | {} | {} |",
+        tr!("gloss.word"),
+        tr!("gloss.phonetic"),
+        tr!("gloss.translation")
+    );
+    println!("|------|------|------|");
+    for lemma in glossary {
+        let info = &lemma.info;
+        let phonetic = info
+            .phonetic
+            .as_deref()
+            .filter(|p| !p.is_empty())
+            .map(|p| format!("/{}/", p.trim_matches('/')))
+            .unwrap_or_default();
+        println!(
+            "| {} | {} | {} |",
+            info.word,
+            phonetic,
+            text::brief_translation(info).replace('|', "\\|")
+        );
+    }
+}
+
+fn print_csv(glossary: &[&Lemma]) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(std::io::stdout());
+    writer.write_record(["word", "phonetic", "translation", "tag", "rank", "count"])?;
+    for lemma in glossary {
+        let info = &lemma.info;
+        writer.write_record([
+            info.word.as_str(),
+            info.phonetic.as_deref().unwrap_or(""),
+            &text::brief_translation(info),
+            info.tag.as_deref().unwrap_or(""),
+            &info.rank().map(|r| r.to_string()).unwrap_or_default(),
+            &lemma.count.to_string(),
+        ])?;
+    }
+    writer.flush()?;
+    Ok(())
+}
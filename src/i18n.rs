@@ -0,0 +1,745 @@
+use clap::ValueEnum;
+use serde::Deserialize;
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+/// 界面语言
+#[derive(ValueEnum, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Lang {
+    #[default]
+    #[value(name = "zh-CN")]
+    #[serde(rename = "zh-CN")]
+    ZhCn,
+    #[value(name = "en")]
+    #[serde(rename = "en")]
+    En,
+}
+
+static CURRENT: OnceLock<Lang> = OnceLock::new();
+
+/// 设置本次运行的界面语言（仅首次调用生效）
+pub fn set(lang: Lang) {
+    let _ = CURRENT.set(lang);
+}
+
+/// 当前界面语言，未设置时为中文
+pub fn current() -> Lang {
+    CURRENT.get().copied().unwrap_or_default()
+}
+
+/// 确定界面语言：`--lang` 优先，其次配置文件，最后是 `LC_ALL` / `LC_MESSAGES` / `LANG`
+///
+/// 需要在 clap 解析前确定，以便帮助信息使用对应语言，因此直接扫描原始参数。
+pub fn detect(args: &[String], config: Option<Lang>) -> Lang {
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        let value = match arg.strip_prefix("--lang") {
+            Some("") => iter.next().map(String::as_str),
+            Some(rest) => rest.strip_prefix('='),
+            None => continue,
+        };
+        if let Some(lang) = value.and_then(|v| Lang::from_str(v, true).ok()) {
+            return lang;
+        }
+    }
+
+    config
+        .or_else(|| {
+            ["LC_ALL", "LC_MESSAGES", "LANG"]
+                .iter()
+                .filter_map(|var| std::env::var(var).ok())
+                .find(|v| !v.is_empty())
+                .and_then(|v| from_locale(&v))
+        })
+        .unwrap_or_default()
+}
+
+/// 由 locale 名称（如 `en_US.UTF-8`）推断语言，`C` / `POSIX` 不作判断
+fn from_locale(locale: &str) -> Option<Lang> {
+    let lang = locale.split(['_', '.', '@', '-']).next().unwrap_or("");
+    match lang {
+        "" | "C" | "POSIX" => None,
+        "zh" => Some(Lang::ZhCn),
+        _ => Some(Lang::En),
+    }
+}
+
+/// 按当前语言获取消息，带参数时依次替换模板中的 `{}`
+#[doc(hidden)]
+#[macro_export]
+macro_rules! tr {
+    ($key:literal) => {
+        $crate::i18n::text($key)
+    };
+    ($key:literal, $($arg:expr),+ $(,)?) => {
+        $crate::i18n::format(
+            $crate::i18n::text($key),
+            &[$(&$arg as &dyn std::fmt::Display),+],
+        )
+    };
+}
+
+/// 查找消息，缺失时返回键名本身
+#[doc(hidden)]
+pub fn text(key: &'static str) -> &'static str {
+    MESSAGES
+        .iter()
+        .find(|(k, _, _)| *k == key)
+        .map(|(_, zh, en)| match current() {
+            Lang::ZhCn => *zh,
+            Lang::En => *en,
+        })
+        .unwrap_or(key)
+}
+
+/// 将参数依次填入模板中的 `{}`
+#[doc(hidden)]
+pub fn format(template: &str, args: &[&dyn Display]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut args = args.iter();
+    let mut parts = template.split("{}");
+    if let Some(first) = parts.next() {
+        out.push_str(first);
+    }
+    for part in parts {
+        if let Some(arg) = args.next() {
+            out.push_str(&arg.to_string());
+        }
+        out.push_str(part);
+    }
+    out
+}
+
+/// 消息目录：(键, 中文, 英文)
+const MESSAGES: &[(&str, &str, &str)] = &[
+    // 通用
+    ("common.unknown", "未知", "unknown"),
+    (
+        "common.no_dict",
+        "尚未安装词库，请先运行 `lango setup`",
+        "No dictionary installed, run `lango setup` first",
+    ),
+    (
+        "common.open_file_failed",
+        "无法打开文件: {}",
+        "Cannot open file: {}",
+    ),
+    // 查询结果
+    ("lookup.not_found", "未找到 \"{}\"", "No results for \"{}\""),
+    (
+        "lookup.corrected",
+        "未找到 \"{}\"，显示 \"{}\" 的结果",
+        "No results for \"{}\", showing \"{}\" instead",
+    ),
+    ("lookup.did_you_mean", "你是不是要找:", "Did you mean:"),
+    (
+        "lookup.hint_online",
+        "提示: 使用 --online 尝试在线查询",
+        "Tip: use --online to try the online dictionary",
+    ),
+    ("lookup.sources", "{} 个来源 · {}ms", "{} sources · {}ms"),
+    (
+        "lookup.picker_hint",
+        "(↑↓ 选择 · 数字直选 · Enter 确认 · Esc 取消)",
+        "(↑↓ move · number to pick · Enter to confirm · Esc to cancel)",
+    ),
+    ("entry.translation", "中文释义", "Chinese translation"),
+    ("entry.definition", "英文定义", "English definition"),
+    ("entry.examples", "例句", "Examples"),
+    ("entry.exchange", "词形变换", "Word forms"),
+    ("source.local", "{} (本地)", "{} (local)"),
+    (
+        "source.online",
+        "Free Dictionary API (在线)",
+        "Free Dictionary API (online)",
+    ),
+    // 词形变换
+    ("exchange.past", "过去式", "past"),
+    ("exchange.past_participle", "过去分词", "past participle"),
+    (
+        "exchange.present_participle",
+        "现在分词",
+        "present participle",
+    ),
+    ("exchange.third_person", "第三人称", "3rd person"),
+    ("exchange.plural", "复数", "plural"),
+    ("exchange.comparative", "比较级", "comparative"),
+    ("exchange.superlative", "最高级", "superlative"),
+    ("exchange.lemma", "原型", "lemma"),
+    ("exchange.lemma_form", "原型变换", "lemma form"),
+    // 搜索结果
+    ("hits.count", "{} 条结果 · {}ms", "{} results · {}ms"),
+    (
+        "hits.page",
+        "第 {}/{} 页 · 共 {} 条 · {}ms",
+        "page {}/{} · {} total · {}ms",
+    ),
+    (
+        "hits.page_out_of_range",
+        "第 {} 页超出范围（共 {} 页，{} 条结果）",
+        "Page {} is out of range ({} pages, {} results)",
+    ),
+    (
+        "hits.next_page",
+        "使用 --page {} 查看下一页",
+        "Use --page {} to see the next page",
+    ),
+    (
+        "hits.no_match",
+        "没有匹配 \"{}\" 的词条",
+        "No entries match \"{}\"",
+    ),
+    (
+        "hits.invalid_regex",
+        "无效的正则表达式: {}",
+        "Invalid regular expression: {}",
+    ),
+    // 词库安装
+    (
+        "setup.no_data_dir",
+        "无法获取用户数据目录",
+        "Cannot determine the user data directory",
+    ),
+    (
+        "setup.no_config_dir",
+        "无法获取用户配置目录",
+        "Cannot determine the user config directory",
+    ),
+    (
+        "setup.no_cache_dir",
+        "无法获取用户缓存目录",
+        "Cannot determine the user cache directory",
+    ),
+    (
+        "setup.write_meta_failed",
+        "无法写入元数据: {}",
+        "Cannot write metadata: {}",
+    ),
+    (
+        "setup.intro",
+        "Lango 需要 ECDICT 离线词库 (770,000+ 词条)",
+        "Lango needs the ECDICT offline dictionary (770,000+ entries)",
+    ),
+    (
+        "setup.intro_size",
+        "首次使用需要下载约 180MB 数据",
+        "The first run downloads about 180MB of data",
+    ),
+    (
+        "setup.confirm",
+        "是否现在下载? [Y/n]: ",
+        "Download now? [Y/n]: ",
+    ),
+    (
+        "setup.later",
+        "你可以稍后运行 `lango setup` 下载词库",
+        "You can run `lango setup` later to download it",
+    ),
+    (
+        "setup.later_import",
+        "或手动下载后执行 `lango setup --import <路径>`",
+        "or download it manually and run `lango setup --import <path>`",
+    ),
+    ("setup.cancelled", "用户取消下载", "Download cancelled"),
+    (
+        "setup.installed_version",
+        "已安装版本: {}",
+        "Installed version: {}",
+    ),
+    (
+        "setup.latest_version",
+        "最新版本:   {}",
+        "Latest version:    {}",
+    ),
+    (
+        "setup.update_available",
+        "有可用更新，运行 `lango update` 升级",
+        "An update is available, run `lango update` to upgrade",
+    ),
+    (
+        "setup.version_unknown",
+        "无法确定已安装词库的版本（早期安装或手动导入），如需覆盖为最新版本请运行 `lango update --force`",
+        "The installed dictionary version is unknown (older install or manual import); run `lango update --force` to replace it with the latest release",
+    ),
+    (
+        "setup.up_to_date",
+        "词库已是最新",
+        "The dictionary is up to date",
+    ),
+    (
+        "setup.fetch_release_failed",
+        "无法获取发布信息，请检查网络连接",
+        "Cannot fetch release information, check your network connection",
+    ),
+    (
+        "setup.fetch_release_status",
+        "获取发布信息失败: HTTP {}",
+        "Failed to fetch release information: HTTP {}",
+    ),
+    (
+        "setup.bad_release",
+        "发布信息格式不正确",
+        "Malformed release information",
+    ),
+    (
+        "setup.no_sqlite_asset",
+        "版本 {} 未提供 SQLite 词库",
+        "Release {} does not provide a SQLite dictionary",
+    ),
+    (
+        "setup.no_digest",
+        "版本 {} 的发布信息未提供 SHA-256 摘要，跳过比对（实际摘要记录在词库元数据中）",
+        "Release {} does not publish a SHA-256 digest, skipping the comparison (the actual digest is recorded in the metadata)",
+    ),
+    (
+        "setup.file_not_found",
+        "文件不存在: {}",
+        "File not found: {}",
+    ),
+    (
+        "setup.copy_failed",
+        "复制文件失败: {} -> {}",
+        "Failed to copy file: {} -> {}",
+    ),
+    (
+        "setup.imported",
+        "词库已导入: {}",
+        "Dictionary imported: {}",
+    ),
+    (
+        "setup.downloading",
+        "正在下载 ECDICT 词库 {}...",
+        "Downloading ECDICT dictionary {}...",
+    ),
+    ("setup.source", "来源: {}", "Source: {}"),
+    ("setup.verifying", "正在校验...", "Verifying..."),
+    (
+        "setup.checksum_mismatch",
+        "校验失败: 期望 SHA-256 {}，实际 {}",
+        "Checksum mismatch: expected SHA-256 {}, got {}",
+    ),
+    ("setup.extracting", "正在解压...", "Extracting..."),
+    (
+        "setup.installed",
+        "词库安装成功: {}",
+        "Dictionary installed: {}",
+    ),
+    (
+        "setup.download_failed",
+        "下载失败，请检查网络连接",
+        "Download failed, check your network connection",
+    ),
+    (
+        "setup.download_status",
+        "下载失败: HTTP {}",
+        "Download failed: HTTP {}",
+    ),
+    (
+        "setup.resuming",
+        "从 {} 处继续下载",
+        "Resuming download at {}",
+    ),
+    ("setup.download_done", "下载完成", "Download complete"),
+    (
+        "setup.replace_failed",
+        "替换词库失败: {} -> {}",
+        "Failed to replace dictionary: {} -> {}",
+    ),
+    (
+        "setup.building_spell_index",
+        "正在建立拼写索引...",
+        "Building spelling index...",
+    ),
+    (
+        "setup.spell_index_failed",
+        "建立拼写索引失败: {}（可稍后运行 lango doctor --fix 重试）",
+        "Failed to build the spelling index: {} (retry later with lango doctor --fix)",
+    ),
+    (
+        "setup.zip_no_db",
+        "zip 文件中未找到 .db 或 .csv 文件",
+        "No .db or .csv file found in the zip archive",
+    ),
+    (
+        "setup.csv_no_header",
+        "CSV 缺少表头",
+        "CSV header is missing",
+    ),
+    (
+        "setup.csv_no_word",
+        "CSV 缺少 word 列",
+        "CSV has no word column",
+    ),
+    ("setup.csv_malformed", "CSV 格式不正确", "Malformed CSV"),
+    (
+        "setup.building_indexes",
+        "正在建立索引...",
+        "Building indexes...",
+    ),
+    (
+        "setup.csv_imported",
+        "已导入 {} 个词条",
+        "Imported {} entries",
+    ),
+    (
+        "setup.db_corrupt",
+        "词库文件损坏或格式不正确",
+        "Dictionary file is corrupt or has an unexpected format",
+    ),
+    ("setup.db_empty", "词库为空", "Dictionary is empty"),
+    (
+        "setup.open_db_failed",
+        "无法打开词库: {}",
+        "Cannot open dictionary: {}",
+    ),
+    // 词库管理
+    (
+        "dicts.invalid_name",
+        "无效的词库名称: {} (仅允许字母、数字、- 和 _)",
+        "Invalid dictionary name: {} (only letters, digits, - and _ are allowed)",
+    ),
+    (
+        "dicts.write_state_failed",
+        "无法写入词库状态: {}",
+        "Cannot write dictionary state: {}",
+    ),
+    (
+        "dicts.not_installed",
+        "未安装词库: {} (使用 `lango dicts list` 查看)",
+        "Dictionary not installed: {} (see `lango dicts list`)",
+    ),
+    ("dicts.enabled", "已启用 {}", "Enabled {}"),
+    ("dicts.disabled", "已禁用 {}", "Disabled {}"),
+    (
+        "dicts.remove_failed",
+        "无法删除词库: {}",
+        "Cannot remove dictionary: {}",
+    ),
+    ("dicts.removed", "已删除 {}", "Removed {}"),
+    (
+        "dicts.none",
+        "尚未安装词库，运行 `lango setup` 下载",
+        "No dictionary installed, run `lango setup` to download one",
+    ),
+    // 在线词典
+    (
+        "online.budget_exhausted",
+        "在线查询次数已达上限，请稍后再试",
+        "Online request budget exhausted, try again later",
+    ),
+    (
+        "online.rate_limited",
+        "在线词典请求过于频繁 (HTTP 429)，请稍后再试",
+        "Too many requests to the online dictionary (HTTP 429), try again later",
+    ),
+    (
+        "online.cooling_down",
+        "在线词典近期限流，请 {} 秒后再试",
+        "The online dictionary was rate limited recently, try again in {}s",
+    ),
+    // 配置文件
+    (
+        "config.read_failed",
+        "无法读取配置文件: {}",
+        "Cannot read config file: {}",
+    ),
+    (
+        "config.malformed",
+        "配置文件格式不正确: {}",
+        "Malformed config file: {}",
+    ),
+    // 文档生成
+    (
+        "docs.man_write_failed",
+        "无法写入手册页: {}",
+        "Cannot write man pages: {}",
+    ),
+    (
+        "docs.man_generated",
+        "手册页已生成: {}",
+        "Man pages generated: {}",
+    ),
+    (
+        "docs.write_failed",
+        "无法写入文档: {}",
+        "Cannot write document: {}",
+    ),
+    ("docs.generated", "文档已生成: {}", "Document generated: {}"),
+    ("docs.arguments", "参数", "Arguments"),
+    ("docs.options", "选项", "Options"),
+    (
+        "docs.possible_values",
+        "（可选值: {}）",
+        " (possible values: {})",
+    ),
+    ("docs.default", "（默认: `{}`）", " (default: `{}`)"),
+    // HTTP 服务
+    (
+        "serve.bind_failed",
+        "无法监听 {}: {}",
+        "Cannot listen on {}: {}",
+    ),
+    (
+        "serve.listening",
+        "Lango 服务已启动: http://{}",
+        "Lango server listening on http://{}",
+    ),
+    ("serve.stop_hint", "按 Ctrl+C 停止", "Press Ctrl+C to stop"),
+    ("serve.missing_query", "缺少参数 q", "Missing parameter q"),
+    (
+        "serve.invalid_param",
+        "参数 {} 无效: {}",
+        "Invalid value for {}: {}",
+    ),
+    (
+        "serve.unknown_endpoint",
+        "未知的接口: {}",
+        "Unknown endpoint: {}",
+    ),
+    (
+        "serve.method_not_allowed",
+        "仅支持 GET 请求",
+        "Only GET requests are supported",
+    ),
+    // 文本分析
+    (
+        "text.read_failed",
+        "无法读取文件: {}",
+        "Cannot read file: {}",
+    ),
+    (
+        "gloss.summary",
+        "共 {} 个生词（文中 {} 个词目，{} 个单词）",
+        "{} glossary words ({} lemmas, {} tokens in the text)",
+    ),
+    ("gloss.unknown", "词库未收录: {}", "Not in dictionary: {}"),
+    ("gloss.word", "单词", "Word"),
+    ("gloss.phonetic", "音标", "Phonetic"),
+    ("gloss.translation", "释义", "Translation"),
+    // 词汇分析
+    ("analyze.overview", "概览", "Overview"),
+    ("analyze.tokens", "单词数", "Tokens"),
+    ("analyze.found_tokens", "可查单词", "In dictionary"),
+    ("analyze.lemmas", "词目数", "Lemmas"),
+    ("analyze.oxford", "牛津 3000", "Oxford 3000"),
+    ("analyze.known", "熟词", "Known words"),
+    ("analyze.share", "{}（{} 个词目）", "{} ({} lemmas)"),
+    ("analyze.tags", "考试标签", "Exam tags"),
+    ("analyze.frequency", "词频排名", "Frequency rank"),
+    ("analyze.no_rank", "无词频", "unranked"),
+    ("analyze.hardest", "最难的词", "Hardest words"),
+    ("analyze.lemma_count", "{} 个词目", "{} lemmas"),
+    // 熟词表
+    (
+        "known.write_failed",
+        "无法写入熟词表: {}",
+        "Cannot write the known-words list: {}",
+    ),
+    (
+        "known.added",
+        "新增 {} 个熟词，共 {} 个",
+        "Added {} known words ({} in total)",
+    ),
+    (
+        "known.removed",
+        "移除 {} 个熟词，剩余 {} 个",
+        "Removed {} known words ({} left)",
+    ),
+    (
+        "known.review_needs_terminal",
+        "复习需要在终端中进行",
+        "Review needs an interactive terminal",
+    ),
+    (
+        "known.review_done",
+        "{} 词汇已全部掌握",
+        "Every {} word is already known",
+    ),
+    (
+        "known.review_hint",
+        "认识按 y / Enter，不认识按 n / 空格，q 退出",
+        "Press y / Enter if you know the word, n / Space if not, q to quit",
+    ),
+    (
+        "known.review_summary",
+        "本次标记 {} 个熟词，共 {} 个",
+        "Marked {} words as known ({} in total)",
+    ),
+    ("known.new_word", "★ 生词", "★ New word"),
+    (
+        "known.mark_hint",
+        "掌握后可用 lango known add {} 标记",
+        "mark it with lango known add {} once learned",
+    ),
+    // 随机抽词
+    (
+        "random.empty_pool",
+        "没有满足条件的单词",
+        "No words match the filters",
+    ),
+    ("random.daily", "每日一词 · {}", "Word of the day · {}"),
+    // 剪贴板监视
+    (
+        "watch.primary_unsupported",
+        "仅 Linux 支持监视主选区",
+        "Watching the primary selection is only supported on Linux",
+    ),
+    (
+        "watch.open_failed",
+        "无法访问剪贴板: {}",
+        "Cannot access the clipboard: {}",
+    ),
+    (
+        "watch.read_failed",
+        "读取剪贴板失败: {}",
+        "Failed to read the clipboard: {}",
+    ),
+    (
+        "watch.started",
+        "正在监视剪贴板，复制单词或短语即可查询",
+        "Watching the clipboard; copy a word or phrase to look it up",
+    ),
+    ("watch.stop_hint", "按 Ctrl+C 停止", "Press Ctrl+C to stop"),
+    // 测验
+    (
+        "quiz.needs_terminal",
+        "测验需要在交互式终端中进行",
+        "Quizzes need an interactive terminal",
+    ),
+    (
+        "quiz.no_mistakes",
+        "没有答错的单词",
+        "No missed words to retry",
+    ),
+    (
+        "quiz.write_failed",
+        "无法写入测验记录 {}",
+        "Cannot write quiz history {}",
+    ),
+    (
+        "quiz.hint",
+        "选择题按 1-4 作答，q 退出；拼写题输入单词后回车，直接回车跳过，输入 :q 退出",
+        "Answer choices with 1-4, q to quit; type spellings and press Enter, empty Enter skips, :q quits",
+    ),
+    ("quiz.letters", "({} 个字母)", "({} letters)"),
+    ("quiz.answer", "正确答案: {}", "Answer: {}"),
+    ("quiz.skipped", "已跳过，答案: {}", "Skipped, answer: {}"),
+    (
+        "quiz.score",
+        "答对 {} / {} ({})，用时 {} 分钟",
+        "{} / {} correct ({}) in {} min",
+    ),
+    (
+        "quiz.no_history",
+        "还没有测验记录，运行 lango quiz 开始",
+        "No quiz history yet; run lango quiz to start",
+    ),
+    ("quiz.stats_overview", "总览", "Overview"),
+    ("quiz.sessions", "测验次数", "Quizzes"),
+    ("quiz.answered", "作答题数", "Answered"),
+    ("quiz.last_week", "最近 7 天", "Last 7 days"),
+    (
+        "quiz.accuracy",
+        "{} 题，正确率 {}",
+        "{} questions, {} correct",
+    ),
+    ("quiz.by_mode", "按题型", "By mode"),
+    ("quiz.mode_choice", "看义选词", "Choice"),
+    ("quiz.mode_spell", "拼写", "Spelling"),
+    ("quiz.mode_reverse", "看词选义", "Reverse"),
+    ("quiz.most_missed", "易错词", "Most missed"),
+    ("quiz.missed_count", "错 {} / {} 次", "missed {} of {}"),
+    (
+        "quiz.review_hint",
+        "运行 lango quiz --mistakes 重做最近答错的单词",
+        "Run lango quiz --mistakes to retry recently missed words",
+    ),
+    // 发音
+    (
+        "pronounce.unavailable",
+        "没有 {} 的发音：在线词典无音频或网络不可用，且未安装 espeak",
+        "No pronunciation for {}: no online audio available and espeak is not installed",
+    ),
+    (
+        "pronounce.no_player",
+        "未找到音频播放器，请安装 mpv 或在配置文件中设置 player",
+        "No audio player found; install mpv or set player in the config file",
+    ),
+    (
+        "pronounce.player_not_found",
+        "找不到播放器命令 {}",
+        "Player command not found: {}",
+    ),
+    (
+        "pronounce.play_failed",
+        "{} 播放失败",
+        "{} failed to play the audio",
+    ),
+    (
+        "pronounce.download_failed",
+        "下载音频失败: {}",
+        "Failed to download audio: {}",
+    ),
+    // 语言服务器
+    (
+        "lsp.unknown_word",
+        "词库中未收录: {}",
+        "Not in dictionary: {}",
+    ),
+    ("lsp.replace_with", "替换为 {}", "Replace with {}"),
+    (
+        "lsp.notification_failed",
+        "处理通知 {} 失败: {}",
+        "Failed to handle notification {}: {}",
+    ),
+    // 诊断信息
+    ("doctor.version", "版本", "Version"),
+    ("doctor.system", "系统", "System"),
+    ("doctor.dictionary", "词库", "Dictionary"),
+    ("doctor.data_dir", "数据目录", "Data dir"),
+    ("doctor.db_path", "词库路径", "Database"),
+    ("doctor.file_size", "文件大小", "Size"),
+    ("doctor.not_installed", "未安装", "not installed"),
+    ("doctor.ecdict_version", "ECDICT 版本", "ECDICT version"),
+    ("doctor.imported", "未知 (本地导入)", "unknown (imported)"),
+    ("doctor.source", "安装来源", "Source"),
+    ("doctor.disabled", "{} (已禁用)", "{} (disabled)"),
+    ("doctor.installed", "已安装词库", "Installed"),
+    ("doctor.config_cache", "配置与缓存", "Config & cache"),
+    ("doctor.config_file", "配置文件", "Config file"),
+    ("doctor.cache_dir", "缓存目录", "Cache dir"),
+    ("doctor.cache_usage", "缓存占用", "Cache usage"),
+    ("doctor.cache_files", "{} 个文件, {}", "{} files, {}"),
+    ("doctor.online", "在线词典", "Online dictionary"),
+    ("doctor.reachability", "连通性", "Reachability"),
+    ("doctor.unreachable", "不可达", "unreachable"),
+    ("doctor.status", "状态", "Status"),
+    ("doctor.open_failed", "无法打开", "cannot open"),
+    ("doctor.entries", "词条数", "Entries"),
+    ("doctor.read_failed", "读取失败", "read failed"),
+    ("doctor.indexes", "索引", "Indexes"),
+    (
+        "doctor.no_indexes",
+        "无 (查询会很慢)",
+        "none (lookups will be slow)",
+    ),
+    ("doctor.spell_index", "拼写索引", "Spell index"),
+    ("doctor.spell_built", "已建立", "built"),
+    (
+        "doctor.spell_missing",
+        "未建立 (运行 lango doctor --fix 建立)",
+        "not built (run lango doctor --fix)",
+    ),
+    (
+        "doctor.fixing_spell_index",
+        "词库 {} 缺少拼写索引",
+        "Dictionary {} has no spelling index",
+    ),
+    (
+        "doctor.nothing_to_fix",
+        "所有词库的拼写索引均已建立",
+        "Every dictionary already has a spelling index",
+    ),
+    ("doctor.journal_mode", "Journal 模式", "Journal mode"),
+    ("doctor.missing", "(不存在)", "(missing)"),
+];
@@ -0,0 +1,315 @@
+use anyhow::{Context, Result};
+use colored::Colorize;
+use console::{Key, Term};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use lango::dict::DictionaryService;
+use lango::tr;
+use lango::types::{DictionaryEntry, LookupResult, WordInfo};
+
+use crate::{picker, setup, text};
+
+/// 已掌握的单词（小写原形），保存在数据目录的 `known.txt` 中，每行一个
+#[derive(Debug, Default)]
+pub struct KnownWords {
+    words: BTreeSet<String>,
+}
+
+/// 熟词表文件路径
+pub fn known_path() -> Result<PathBuf> {
+    Ok(setup::data_dir()?.join("known.txt"))
+}
+
+impl KnownWords {
+    /// 读取熟词表，文件不存在时为空
+    pub fn load() -> Result<Self> {
+        let path = known_path()?;
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content =
+            fs::read_to_string(&path).with_context(|| tr!("text.read_failed", path.display()))?;
+        Ok(Self::parse(&content))
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = known_path()?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, self.to_text()).with_context(|| tr!("known.write_failed", path.display()))
+    }
+
+    /// 解析熟词表：整行为一个条目（词组中的空格保留），忽略空行与 `#` 注释
+    fn parse(content: &str) -> Self {
+        Self {
+            words: content
+                .lines()
+                .map(str::trim)
+                .filter(|l| !l.is_empty() && !l.starts_with('#'))
+                .map(str::to_lowercase)
+                .collect(),
+        }
+    }
+
+    fn to_text(&self) -> String {
+        let mut content = String::new();
+        for word in &self.words {
+            content.push_str(word);
+            content.push('\n');
+        }
+        content
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.words.contains(&word.to_lowercase())
+    }
+
+    /// 添加单词，返回是否为新加入
+    pub fn insert(&mut self, word: &str) -> bool {
+        self.words.insert(word.to_lowercase())
+    }
+
+    /// 移除单词，返回是否存在
+    pub fn remove(&mut self, word: &str) -> bool {
+        self.words.remove(&word.to_lowercase())
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &String> {
+        self.words.iter()
+    }
+
+    /// 单词或其原形是否已掌握
+    pub fn knows(&self, service: &DictionaryService, word: &str) -> Result<bool> {
+        if self.contains(word) {
+            return Ok(true);
+        }
+        Ok(service
+            .lemmatize(word)?
+            .is_some_and(|info| self.contains(&info.word)))
+    }
+}
+
+/// 熟词表中保存的形式：词库可用时还原为原形，否则保持原样
+fn normalize(service: Option<&DictionaryService>, word: &str) -> Result<String> {
+    let lemma = match service {
+        Some(service) => service.lemmatize(word)?.map(|info| info.word),
+        None => None,
+    };
+    Ok(lemma.unwrap_or_else(|| word.to_string()).to_lowercase())
+}
+
+/// 将单词标记为已掌握（按原形保存）
+pub fn add(service: Option<&DictionaryService>, words: &[String]) -> Result<()> {
+    let mut known = KnownWords::load()?;
+    let mut added = 0;
+    for word in words {
+        if known.insert(&normalize(service, word)?) {
+            added += 1;
+        }
+    }
+    known.save()?;
+    print_changed(added, known.len());
+    Ok(())
+}
+
+/// 从熟词表中移除单词（与添加时一样按原形匹配）
+pub fn remove(service: Option<&DictionaryService>, words: &[String]) -> Result<()> {
+    let mut known = KnownWords::load()?;
+    let mut removed = 0;
+    for word in words {
+        // 同时移除原样保存的词形（词库不可用时添加的单词）
+        let lemma = known.remove(&normalize(service, word)?);
+        if known.remove(word) || lemma {
+            removed += 1;
+        }
+    }
+    known.save()?;
+    println!();
+    println!(
+        "  {} {}",
+        "✓".green(),
+        tr!("known.removed", removed, known.len())
+    );
+    println!();
+    Ok(())
+}
+
+/// 列出熟词表，每行一个单词（便于管道处理）
+pub fn list() -> Result<()> {
+    for word in KnownWords::load()?.iter() {
+        println!("{}", word);
+    }
+    Ok(())
+}
+
+/// 从文件（每行一个单词，按原形保存）与考试标签导入熟词
+pub fn import(
+    service: Option<&DictionaryService>,
+    file: Option<&Path>,
+    tags: &[String],
+) -> Result<()> {
+    let mut known = KnownWords::load()?;
+    let mut added = 0;
+
+    if let Some(path) = file {
+        for word in text::read_word_list(path)? {
+            if known.insert(&normalize(service, &word)?) {
+                added += 1;
+            }
+        }
+    }
+    if let Some(service) = service {
+        for tag in tags {
+            for info in service.tagged_words(tag)? {
+                if known.insert(&info.word) {
+                    added += 1;
+                }
+            }
+        }
+    }
+
+    known.save()?;
+    print_changed(added, known.len());
+    Ok(())
+}
+
+fn print_changed(added: usize, total: usize) {
+    println!();
+    println!("  {} {}", "✓".green(), tr!("known.added", added, total));
+    println!();
+}
+
+/// 复习：逐个显示尚未掌握的单词（按词频从高到低），由用户标记是否认识
+pub fn review(service: &DictionaryService, tag: &str, count: usize) -> Result<()> {
+    if !picker::is_interactive() {
+        anyhow::bail!(tr!("known.review_needs_terminal"));
+    }
+
+    let mut known = KnownWords::load()?;
+    let pool: Vec<WordInfo> = service
+        .tagged_words(tag)?
+        .into_iter()
+        .filter(|info| !known.contains(&info.word))
+        .take(count)
+        .collect();
+
+    if pool.is_empty() {
+        println!();
+        println!("  {} {}", "✓".green(), tr!("known.review_done", tag));
+        println!();
+        return Ok(());
+    }
+
+    let term = Term::stdout();
+    term.write_line("")?;
+    term.write_line(&format!("  {}", tr!("known.review_hint").dimmed()))?;
+    term.write_line("")?;
+
+    let mut marked = 0;
+    for (i, info) in pool.iter().enumerate() {
+        let phonetic = info
+            .phonetic
+            .as_deref()
+            .filter(|p| !p.is_empty())
+            .map(|p| format!(" /{}/", p.trim_matches('/')))
+            .unwrap_or_default();
+        term.write_line(&format!(
+            "  {} {}{}",
+            format!("[{}/{}]", i + 1, pool.len()).dimmed(),
+            info.word.bright_blue().bold(),
+            phonetic.cyan()
+        ))?;
+
+        let answer = loop {
+            match term.read_key()? {
+                Key::Char('y') | Key::Enter => break Some(true),
+                Key::Char('n') | Key::Char(' ') => break Some(false),
+                Key::Char('q') | Key::Escape => break None,
+                _ => {}
+            }
+        };
+        let Some(is_known) = answer else {
+            break;
+        };
+
+        let mark = if is_known {
+            known.insert(&info.word);
+            marked += 1;
+            "✓".green()
+        } else {
+            "✗".red()
+        };
+        term.write_line(&format!(
+            "    {} {}",
+            mark,
+            crate::text::brief_translation(info).green()
+        ))?;
+    }
+
+    known.save()?;
+    term.write_line("")?;
+    term.write_line(&format!(
+        "  {} {}",
+        "──".dimmed(),
+        tr!("known.review_summary", marked, known.len()).dimmed()
+    ))?;
+    term.write_line("")?;
+    Ok(())
+}
+
+/// 查询结果中的单词尚未掌握时，在词条下方提示为生词
+///
+/// 熟词表为空时不提示，以免所有单词都被标为生词。
+pub fn annotate(
+    service: &DictionaryService,
+    known: &KnownWords,
+    result: &LookupResult,
+) -> Result<()> {
+    let entry: &DictionaryEntry = match result {
+        LookupResult::Found(entry) | LookupResult::Corrected(entry) => entry,
+        _ => return Ok(()),
+    };
+    if known.is_empty() || known.knows(service, &entry.word)? {
+        return Ok(());
+    }
+    println!(
+        "  {}  {}",
+        tr!("known.new_word").yellow().bold(),
+        tr!("known.mark_hint", entry.word).dimmed()
+    );
+    println!();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phrases_survive_a_save_and_load() {
+        let mut known = KnownWords::default();
+        known.insert("take off");
+        known.insert("Apple");
+
+        let reloaded = KnownWords::parse(&known.to_text());
+        assert_eq!(reloaded.iter().collect::<Vec<_>>(), ["apple", "take off"]);
+        assert!(!reloaded.contains("take"));
+    }
+
+    #[test]
+    fn parse_ignores_blank_lines_and_comments() {
+        let known = KnownWords::parse("# 熟词\n\n  Look Up  \nhello\n");
+        assert_eq!(known.iter().collect::<Vec<_>>(), ["hello", "look up"]);
+    }
+}
@@ -0,0 +1,44 @@
+//! Lango 词典库：ECDICT 本地词典、Free Dictionary API 在线词典与查询编排
+//!
+//! `lango` 命令行工具构建在本库之上，其他 Rust 程序也可以直接使用同一套查询逻辑：
+//!
+//! ```no_run
+//! use lango::dict::DictionaryService;
+//! use lango::dict::ecdict::EcdictDictionary;
+//! use lango::types::{LookupOptions, LookupResult};
+//!
+//! # fn main() -> anyhow::Result<()> {
+//! let local = EcdictDictionary::open("stardict.db")?;
+//! let service = DictionaryService::new(vec![Box::new(local)], None);
+//!
+//! let mut options = LookupOptions::default();
+//! options.auto_correct = true;
+//! match service.lookup("hello", &options)? {
+//!     LookupResult::Found(entry) | LookupResult::Corrected(entry) => {
+//!         println!("{}: {:?}", entry.word, entry.translation);
+//!     }
+//!     _ => println!("未找到"),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # 版本兼容性
+//!
+//! 本库遵循[语义化版本](https://semver.org/lang/zh-CN/)，公开 API 包括 [`dict`]、
+//! [`types`]、[`formatter`] 与 [`i18n`] 模块中未标记 `#[doc(hidden)]` 的条目：
+//!
+//! - 1.0 之前，不兼容的变更只在次版本号升级时发生（如 0.1 → 0.2），修订版本只做兼容修改；
+//! - 标记为 `#[non_exhaustive]` 的枚举可能在次版本中增加变体，匹配时请保留通配分支；
+//! - 结构体新增公开字段视为不兼容变更，标记为 `#[non_exhaustive]` 的结构体（如
+//!   [`types::LookupOptions`]）除外，这类结构体请通过 `Default` 创建；
+//! - 其他词库可实现 [`dict::LocalDictionary`]（及 [`dict::Dictionary`]）后交给
+//!   [`dict::DictionaryService`] 查询，trait 新增的方法都会带有默认实现；
+//! - 输出文本（如 [`formatter`] 的排版与 [`i18n`] 的消息内容）不属于兼容性保证范围。
+//!
+//! 词库的下载、安装与多词库管理属于命令行工具，不在本库中提供。
+
+pub mod dict;
+pub mod formatter;
+pub mod i18n;
+pub mod types;
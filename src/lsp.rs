@@ -0,0 +1,437 @@
+use anyhow::Result;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+    Notification as NotificationTrait, PublishDiagnostics,
+};
+use lsp_types::request::{CodeActionRequest, HoverRequest, Request as RequestTrait};
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams,
+    CodeActionProviderCapability, Diagnostic, DiagnosticSeverity, DidChangeTextDocumentParams,
+    DidCloseTextDocumentParams, DidOpenTextDocumentParams, Hover, HoverContents, HoverParams,
+    HoverProviderCapability, InitializeParams, MarkupContent, MarkupKind, Position,
+    PublishDiagnosticsParams, Range, ServerCapabilities, TextDocumentSyncCapability,
+    TextDocumentSyncKind, TextEdit, Uri, WorkspaceEdit,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+
+use lango::dict::DictionaryService;
+use lango::tr;
+use lango::types::DictionaryEntry;
+
+/// 诊断信息的来源标识
+const SOURCE: &str = "lango";
+/// 短于此长度的单词不做拼写检查
+const MIN_CHECK_LEN: usize = 3;
+/// 快速修复提供的候选数
+const MAX_FIXES: usize = 5;
+/// 悬停显示的英文释义行数上限
+const MAX_DEFINITION_LINES: usize = 5;
+
+/// 通过标准输入输出运行语言服务器
+///
+/// 提供悬停释义；启用诊断后（`--diagnostics` 或初始化选项 `{"diagnostics": true}`）
+/// 将词库未收录的单词标记为拼写问题，并以拼写建议作为快速修复。
+pub fn run(service: DictionaryService, diagnostics: bool) -> Result<()> {
+    let (connection, io_threads) = Connection::stdio();
+
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
+        ..Default::default()
+    };
+
+    let (id, params) = connection.initialize_start()?;
+    let params: InitializeParams = serde_json::from_value(params)?;
+    let diagnostics = diagnostics
+        || params
+            .initialization_options
+            .as_ref()
+            .and_then(|o| o.get("diagnostics"))
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+    connection.initialize_finish(
+        id,
+        json!({
+            "capabilities": capabilities,
+            "serverInfo": { "name": "lango", "version": env!("CARGO_PKG_VERSION") },
+        }),
+    )?;
+
+    let mut server = Server {
+        service,
+        diagnostics,
+        documents: HashMap::new(),
+        known: HashMap::new(),
+    };
+    server.main_loop(&connection)?;
+
+    // 关闭连接后才能结束 IO 线程
+    drop(connection);
+    io_threads.join()?;
+    Ok(())
+}
+
+struct Server {
+    service: DictionaryService,
+    diagnostics: bool,
+    /// 已打开文档的全文
+    documents: HashMap<Uri, String>,
+    /// 单词（小写）是否被词库收录的缓存
+    known: HashMap<String, bool>,
+}
+
+impl Server {
+    fn main_loop(&mut self, connection: &Connection) -> Result<()> {
+        for msg in &connection.receiver {
+            match msg {
+                Message::Request(req) => {
+                    if connection.handle_shutdown(&req)? {
+                        return Ok(());
+                    }
+                    let response = self.handle_request(req);
+                    connection.sender.send(Message::Response(response))?;
+                }
+                Message::Notification(not) => {
+                    // 通知没有响应，出错时只记录到 stderr（stdout 用于协议通信），继续处理后续消息
+                    let method = not.method.clone();
+                    match self.handle_notification(not) {
+                        Ok(Some(publish)) => {
+                            connection.sender.send(Message::Notification(publish))?;
+                        }
+                        Ok(None) => {}
+                        Err(e) => eprintln!("{}", tr!("lsp.notification_failed", method, e)),
+                    }
+                }
+                Message::Response(_) => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_request(&mut self, req: Request) -> Response {
+        let result = match req.method.as_str() {
+            HoverRequest::METHOD => {
+                parse::<HoverParams>(req.params).and_then(|p| to_value(self.hover(p)?))
+            }
+            CodeActionRequest::METHOD => {
+                parse::<CodeActionParams>(req.params).and_then(|p| to_value(self.code_actions(p)?))
+            }
+            _ => {
+                return Response::new_err(
+                    req.id,
+                    ErrorCode::MethodNotFound as i32,
+                    format!("unhandled method: {}", req.method),
+                );
+            }
+        };
+
+        match result {
+            Ok(value) => Response::new_ok(req.id, value),
+            Err(e) => Response::new_err(req.id, ErrorCode::InternalError as i32, e.to_string()),
+        }
+    }
+
+    /// 更新文档，需要时返回重新计算的诊断
+    fn handle_notification(&mut self, not: Notification) -> Result<Option<Notification>> {
+        let uri = match not.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: DidOpenTextDocumentParams = parse(not.params)?;
+                let doc = params.text_document;
+                self.documents.insert(doc.uri.clone(), doc.text);
+                doc.uri
+            }
+            DidChangeTextDocument::METHOD => {
+                let params: DidChangeTextDocumentParams = parse(not.params)?;
+                // 全量同步：最后一次变更即为完整内容
+                if let Some(change) = params.content_changes.into_iter().last() {
+                    self.documents
+                        .insert(params.text_document.uri.clone(), change.text);
+                }
+                params.text_document.uri
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: DidCloseTextDocumentParams = parse(not.params)?;
+                self.documents.remove(&params.text_document.uri);
+                if !self.diagnostics {
+                    return Ok(None);
+                }
+                // 清除已关闭文档的诊断
+                return Ok(Some(publish(params.text_document.uri, Vec::new())));
+            }
+            _ => return Ok(None),
+        };
+
+        if !self.diagnostics {
+            return Ok(None);
+        }
+        let diagnostics = self.check_document(&uri)?;
+        Ok(Some(publish(uri, diagnostics)))
+    }
+
+    fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
+        let position = params.text_document_position_params;
+        let Some(text) = self.documents.get(&position.text_document.uri) else {
+            return Ok(None);
+        };
+        let Some((word, range)) = word_at(text, position.position) else {
+            return Ok(None);
+        };
+
+        // 悬停在未收录的词上很常见，不做拼写建议（需要扫描全表），以免阻塞消息循环
+        let Some(entry) = self.service.lookup_exact(&word)? else {
+            return Ok(None);
+        };
+
+        Ok(Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: hover_markdown(&entry),
+            }),
+            range: Some(range),
+        }))
+    }
+
+    /// 为本服务器产生的拼写诊断提供替换建议
+    fn code_actions(&self, params: CodeActionParams) -> Result<Vec<CodeActionOrCommand>> {
+        let uri = params.text_document.uri;
+        let mut actions = Vec::new();
+
+        for diagnostic in params.context.diagnostics {
+            if diagnostic.source.as_deref() != Some(SOURCE) {
+                continue;
+            }
+            let Some(word) = diagnostic
+                .data
+                .as_ref()
+                .and_then(|d| d.get("word"))
+                .and_then(Value::as_str)
+            else {
+                continue;
+            };
+
+            let suggestions = self.service.suggest(&word.to_lowercase(), MAX_FIXES)?;
+            for (i, suggestion) in suggestions.into_iter().enumerate() {
+                let replacement = match_case(word, &suggestion.word);
+                let edit = TextEdit {
+                    range: diagnostic.range,
+                    new_text: replacement.clone(),
+                };
+                actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+                    title: tr!("lsp.replace_with", replacement),
+                    kind: Some(CodeActionKind::QUICKFIX),
+                    diagnostics: Some(vec![diagnostic.clone()]),
+                    edit: Some(WorkspaceEdit {
+                        changes: Some(HashMap::from([(uri.clone(), vec![edit])])),
+                        ..Default::default()
+                    }),
+                    is_preferred: Some(i == 0),
+                    ..Default::default()
+                }));
+            }
+        }
+
+        Ok(actions)
+    }
+
+    /// 检查文档中的单词，返回词库未收录的单词
+    fn check_document(&mut self, uri: &Uri) -> Result<Vec<Diagnostic>> {
+        let Some(text) = self.documents.get(uri) else {
+            return Ok(Vec::new());
+        };
+
+        let mut diagnostics = Vec::new();
+        for (word, range) in words(text) {
+            if !should_check(&word) {
+                continue;
+            }
+            let key = word.to_lowercase();
+            let known = match self.known.get(&key) {
+                Some(&known) => known,
+                None => {
+                    let known = self.service.contains(&key)?;
+                    self.known.insert(key, known);
+                    known
+                }
+            };
+            if !known {
+                diagnostics.push(Diagnostic {
+                    range,
+                    severity: Some(DiagnosticSeverity::INFORMATION),
+                    source: Some(SOURCE.to_string()),
+                    message: tr!("lsp.unknown_word", word),
+                    data: Some(json!({ "word": word })),
+                    ..Default::default()
+                });
+            }
+        }
+        Ok(diagnostics)
+    }
+}
+
+fn parse<T: DeserializeOwned>(params: Value) -> Result<T> {
+    Ok(serde_json::from_value(params)?)
+}
+
+fn to_value<T: serde::Serialize>(value: T) -> Result<Value> {
+    Ok(serde_json::to_value(value)?)
+}
+
+fn publish(uri: Uri, diagnostics: Vec<Diagnostic>) -> Notification {
+    Notification::new(
+        PublishDiagnostics::METHOD.to_string(),
+        PublishDiagnosticsParams {
+            uri,
+            diagnostics,
+            version: None,
+        },
+    )
+}
+
+/// 悬停内容：单词、音标、中文释义与英文释义
+fn hover_markdown(entry: &DictionaryEntry) -> String {
+    let mut md = format!("**{}**", entry.word);
+    if let Some(ref phonetic) = entry.phonetic {
+        md.push_str(&format!("  `/{}/`", phonetic.trim_matches('/')));
+    }
+
+    let lines = |text: &str, max: usize| -> Vec<String> {
+        text.lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty())
+            .take(max)
+            .map(str::to_string)
+            .collect()
+    };
+    if let Some(ref translation) = entry.translation {
+        md.push_str("\n\n");
+        md.push_str(&lines(translation, usize::MAX).join("  \n"));
+    }
+    if let Some(ref definition) = entry.definition {
+        md.push_str("\n\n---\n\n");
+        md.push_str(&lines(definition, MAX_DEFINITION_LINES).join("  \n"));
+    }
+    md
+}
+
+/// 只检查普通英文单词：跳过过短的词、全大写缩写与驼峰式标识符
+fn should_check(word: &str) -> bool {
+    word.len() >= MIN_CHECK_LEN && !word.chars().skip(1).any(|c| c.is_ascii_uppercase())
+}
+
+/// 按原词的首字母大小写调整替换词
+fn match_case(original: &str, replacement: &str) -> String {
+    if original.starts_with(|c: char| c.is_ascii_uppercase()) {
+        let mut chars = replacement.chars();
+        match chars.next() {
+            Some(first) => first.to_uppercase().chain(chars).collect(),
+            None => String::new(),
+        }
+    } else {
+        replacement.to_string()
+    }
+}
+
+/// 文档中的英文单词及其范围（列号按 UTF-16 计算）
+///
+/// 由字母、数字或下划线组成的标记中，只有纯 ASCII 字母的才视为单词，
+/// 以免把代码中的 `foo_bar`、`utf8` 之类当作拼写错误。
+fn words(text: &str) -> Vec<(String, Range)> {
+    let mut result = Vec::new();
+    for (line_no, line) in text.lines().enumerate() {
+        let mut token = String::new();
+        let mut start = 0u32;
+        let mut col = 0u32;
+        for c in line.chars().chain(std::iter::once(' ')) {
+            if c.is_alphanumeric() || c == '_' {
+                if token.is_empty() {
+                    start = col;
+                }
+                token.push(c);
+            } else if !token.is_empty() {
+                if token.chars().all(|c| c.is_ascii_alphabetic()) {
+                    let line_no = line_no as u32;
+                    result.push((
+                        std::mem::take(&mut token),
+                        Range::new(Position::new(line_no, start), Position::new(line_no, col)),
+                    ));
+                } else {
+                    token.clear();
+                }
+            }
+            col += c.len_utf16() as u32;
+        }
+    }
+    result
+}
+
+/// 光标所在的单词
+fn word_at(text: &str, position: Position) -> Option<(String, Range)> {
+    words(text).into_iter().find(|(_, range)| {
+        range.start.line == position.line
+            && range.start.character <= position.character
+            && position.character <= range.end.character
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(line: u32, start: u32, end: u32) -> Range {
+        Range::new(Position::new(line, start), Position::new(line, end))
+    }
+
+    #[test]
+    fn words_skip_identifiers_with_digits_or_underscores() {
+        let found: Vec<String> = words("let utf8 = foo_bar(word);")
+            .into_iter()
+            .map(|(w, _)| w)
+            .collect();
+        assert_eq!(found, ["let", "word"]);
+    }
+
+    #[test]
+    fn words_report_utf16_columns() {
+        let found = words("tea\n  中文 hello");
+        assert_eq!(found[0], ("tea".to_string(), range(0, 0, 3)));
+        assert_eq!(found[1], ("hello".to_string(), range(1, 5, 10)));
+    }
+
+    #[test]
+    fn words_count_non_bmp_characters_as_two_units() {
+        // 😀 在 UTF-16 中占两个码元
+        let found = words("😀 hello");
+        assert_eq!(found, [("hello".to_string(), range(0, 3, 8))]);
+    }
+
+    #[test]
+    fn word_at_finds_the_word_under_the_cursor() {
+        let text = "say 😀 hello world";
+        let (word, r) = word_at(text, Position::new(0, 7)).unwrap();
+        assert_eq!((word.as_str(), r), ("hello", range(0, 7, 12)));
+        // 单词末尾也算在单词上
+        assert_eq!(word_at(text, Position::new(0, 12)).unwrap().0, "hello");
+        assert_eq!(word_at(text, Position::new(0, 5)), None);
+        assert_eq!(word_at(text, Position::new(1, 0)), None);
+    }
+
+    #[test]
+    fn should_check_skips_short_words_acronyms_and_camel_case() {
+        assert!(should_check("hello"));
+        assert!(should_check("Hello"));
+        assert!(!should_check("of"));
+        assert!(!should_check("HTTP"));
+        assert!(!should_check("camelCase"));
+    }
+
+    #[test]
+    fn match_case_follows_the_first_letter() {
+        assert_eq!(match_case("Teh", "the"), "The");
+        assert_eq!(match_case("teh", "the"), "the");
+        assert_eq!(match_case("Teh", ""), "");
+    }
+}
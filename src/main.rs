@@ -1,79 +1,308 @@
+mod analyze;
 mod cli;
-mod dict;
-mod formatter;
+mod completions;
+mod config;
+mod dicts;
+mod docs;
+mod doctor;
+mod gloss;
+mod known;
+mod lsp;
+mod picker;
+mod pronounce;
+mod quiz;
+mod random;
+mod report;
+mod serve;
 mod setup;
-mod types;
+mod text;
+mod watch;
 
 use anyhow::Result;
-use clap::Parser;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
-use cli::{Cli, Commands};
-use dict::DictionaryService;
-use dict::ecdict::EcdictDictionary;
-use dict::online::OnlineDictionary;
-use types::LookupOptions;
+use lango::dict::DictionaryService;
+use lango::dict::online::OnlineDictionary;
+use lango::types::{LookupOptions, LookupResult, WordPattern};
+use lango::{formatter, i18n, tr};
+
+use cli::{Cli, Commands, DictsAction, KnownAction};
+use config::Config;
 
 fn main() -> Result<()> {
-    let cli = Cli::parse();
+    // 补全脚本调用的隐藏命令，不经过 clap 以免出现在补全候选中
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some(completions::COMPLETE_COMMAND) {
+        return completions::complete_words(args.get(2).map(String::as_str).unwrap_or(""));
+    }
+
+    // 帮助信息也需要本地化，因此在解析参数前确定界面语言
+    i18n::set(i18n::detect(
+        &args,
+        Config::load().ok().and_then(|c| c.lang),
+    ));
+    let cli = cli::parse();
 
     // 处理子命令
     if let Some(cmd) = &cli.command {
         match cmd {
-            Commands::Setup { import_path } => {
+            Commands::Setup {
+                import_path,
+                name,
+                check_update,
+            } => {
                 if let Some(path) = import_path {
-                    setup::import_db(path)?;
+                    setup::import_db(path, name.as_deref())?;
+                } else if *check_update {
+                    setup::check_update()?;
                 } else {
                     setup::interactive_setup()?;
                 }
                 return Ok(());
             }
+            Commands::Update { force } => {
+                setup::update(*force)?;
+                return Ok(());
+            }
+            Commands::Phrase {
+                pattern,
+                any_order,
+                limit,
+            } => {
+                let service = local_service(&cli)?;
+                let start = Instant::now();
+                let hits = service.search_phrases(pattern, *any_order, *limit)?;
+                formatter::print_hits(&pattern.join(" "), &hits, start.elapsed());
+                return Ok(());
+            }
+            Commands::Match {
+                pattern,
+                regex,
+                len,
+                page,
+                limit,
+            } => {
+                let (title, word_pattern) = match (pattern, regex) {
+                    (_, Some(re)) => (re.clone(), WordPattern::Regex(re.clone())),
+                    (Some(glob), None) => (glob.clone(), WordPattern::Glob(glob.clone())),
+                    (None, None) => unreachable!("clap 保证至少提供一种模式"),
+                };
+                let page = usize::try_from(*page).unwrap_or(usize::MAX);
+                // SQLite 的 OFFSET 为 i64，超出范围时只统计总数，显示为页码超出范围
+                let offset = (page - 1)
+                    .checked_mul(*limit)
+                    .filter(|offset| i64::try_from(*offset).is_ok());
+                let service = local_service(&cli)?;
+                let start = Instant::now();
+                let (total, hits) = match offset {
+                    Some(offset) => service.match_words(&word_pattern, *len, offset, *limit)?,
+                    None => (
+                        service.match_words(&word_pattern, *len, 0, 0)?.0,
+                        Vec::new(),
+                    ),
+                };
+                formatter::print_hit_page(&title, &hits, page, *limit, total, start.elapsed());
+                return Ok(());
+            }
+            Commands::Dicts { action } => {
+                match action {
+                    DictsAction::List => dicts::print_list()?,
+                    DictsAction::Enable { name } => dicts::set_enabled(name, true)?,
+                    DictsAction::Disable { name } => dicts::set_enabled(name, false)?,
+                    DictsAction::Remove { name } => dicts::remove(name)?,
+                }
+                return Ok(());
+            }
+            Commands::Completions { shell } => {
+                completions::print(*shell)?;
+                return Ok(());
+            }
+            Commands::Docs { format, out_dir } => {
+                docs::generate(*format, out_dir.as_deref())?;
+                return Ok(());
+            }
+            Commands::Serve { port, host, cors } => {
+                let service = local_service(&cli)?.with_online(online_dictionary(&cli));
+                serve::run(
+                    service,
+                    lookup_options(&cli, &Config::load()?),
+                    host,
+                    *port,
+                    cors.as_deref(),
+                )?;
+                return Ok(());
+            }
+            Commands::Gloss {
+                file,
+                known,
+                min_rank,
+                level,
+                no_known,
+                format,
+            } => {
+                let mut known = match known {
+                    Some(path) => text::read_word_list(path)?,
+                    None => Default::default(),
+                };
+                if !no_known {
+                    known.extend(known::KnownWords::load()?.iter().cloned());
+                }
+                let difficulty = gloss::Difficulty::new(known, *min_rank, level.as_deref());
+                gloss::run(&local_service(&cli)?, file, &difficulty, *format)?;
+                return Ok(());
+            }
+            Commands::Analyze { file, top, json } => {
+                analyze::run(&local_service(&cli)?, file, *top, *json)?;
+                return Ok(());
+            }
+            Commands::Known { action } => {
+                match action {
+                    KnownAction::Add { words } => {
+                        known::add(local_service(&cli).ok().as_ref(), words)?
+                    }
+                    KnownAction::Remove { words } => {
+                        known::remove(local_service(&cli).ok().as_ref(), words)?
+                    }
+                    KnownAction::List => known::list()?,
+                    KnownAction::Import { file, tags } => {
+                        // 按标签导入需要词库；仅从文件导入时词库只用于还原原形
+                        let service = if tags.is_empty() {
+                            local_service(&cli).ok()
+                        } else {
+                            Some(local_service(&cli)?)
+                        };
+                        known::import(service.as_ref(), file.as_deref(), tags)?;
+                    }
+                    KnownAction::Review { tag, count } => {
+                        known::review(&local_service(&cli)?, tag, *count)?
+                    }
+                }
+                return Ok(());
+            }
+            Commands::Watch {
+                primary, interval, ..
+            } => {
+                let service = local_service(&cli)?.with_online(online_dictionary(&cli));
+                let mut clipboard = watch::SystemClipboard::new(*primary)?;
+                watch::run(
+                    &mut clipboard,
+                    &service,
+                    &lookup_options(&cli, &Config::load()?),
+                    Duration::from_millis(*interval),
+                )?;
+                return Ok(());
+            }
+            Commands::Random { pool } => {
+                let service = local_service(&cli)?.with_online(online_dictionary(&cli));
+                random::random(
+                    &service,
+                    &pool.filter(),
+                    &lookup_options(&cli, &Config::load()?),
+                )?;
+                return Ok(());
+            }
+            Commands::Daily { pool } => {
+                let service = local_service(&cli)?.with_online(online_dictionary(&cli));
+                random::daily(
+                    &service,
+                    &pool.filter(),
+                    &lookup_options(&cli, &Config::load()?),
+                )?;
+                return Ok(());
+            }
+            Commands::Quiz {
+                mode,
+                count,
+                mistakes,
+                stats,
+                pool,
+            } => {
+                if *stats {
+                    quiz::print_stats()?;
+                } else {
+                    let service = local_service(&cli)?;
+                    quiz::run(&service, &pool.filter(), *mode, *count, *mistakes)?;
+                }
+                return Ok(());
+            }
+            Commands::Lsp { diagnostics } => {
+                lsp::run(local_service(&cli)?, *diagnostics)?;
+                return Ok(());
+            }
+            Commands::Doctor { fix } => {
+                doctor::run(*fix)?;
+                return Ok(());
+            }
         }
     }
 
     // 拼接查询词
     let query = cli.query.join(" ");
     if query.is_empty() {
-        use clap::CommandFactory;
-        Cli::command().print_help()?;
+        cli::command().print_help()?;
         println!();
         return Ok(());
     }
 
     // 检查词库是否存在
-    if !setup::is_db_installed() && !cli.force_online {
+    if !dicts::any_installed() && !cli.force_online {
         setup::interactive_setup()?;
     }
 
     // 初始化词典服务
-    let local = if let Ok(path) = setup::db_path() {
-        if path.exists() {
-            EcdictDictionary::open(&path).ok()
-        } else {
-            None
-        }
-    } else {
-        None
-    };
-
-    let online = Some(OnlineDictionary::new());
-    let service = DictionaryService::new(local, online);
-
-    // 在线模式默认显示英文定义（因为在线API无中文翻译）
-    let show_english = cli.show_english || cli.force_online;
+    let locals = dicts::open_selected(&cli.dicts)?;
+    let service = DictionaryService::new(locals, Some(online_dictionary(&cli)));
+    let config = Config::load()?;
+    let options = lookup_options(&cli, &config);
+    let known = known::KnownWords::load()?;
 
-    let options = LookupOptions {
-        show_english,
-        show_examples: cli.show_examples,
-        force_online: cli.force_online,
-        max_examples: cli.max_examples,
-    };
+    // --all：分别查询所有词典并对比输出
+    if cli.all_sources {
+        let start = Instant::now();
+        let results = service.lookup_all(&query, &options);
+        let elapsed = start.elapsed();
+        formatter::print_all(
+            &results,
+            &query,
+            options.show_english,
+            options.show_examples,
+            elapsed,
+        );
+        if cli.pronounce {
+            if let Some(entry) = results.iter().find_map(|r| r.entry.as_ref()) {
+                pronounce::pronounce_entry(service.online(), &config, entry);
+            }
+        }
+        return Ok(());
+    }
 
     // 执行查询并计时
     let start = Instant::now();
     let result = service.lookup(&query, &options)?;
     let elapsed = start.elapsed();
 
+    // 终端下交互式选择拼写建议，并直接显示所选词条
+    if let LookupResult::Suggestions(ref suggestions) = result {
+        if !cli.no_interactive && picker::is_interactive() {
+            if let Some(word) = picker::pick_suggestion(&query, suggestions)? {
+                let start = Instant::now();
+                let picked = service.lookup(&word, &options)?;
+                formatter::print_result(
+                    &picked,
+                    &word,
+                    options.show_english,
+                    options.show_examples,
+                    start.elapsed(),
+                );
+                known::annotate(&service, &known, &picked)?;
+                if cli.pronounce {
+                    pronounce::pronounce(service.online(), &config, &picked);
+                }
+            }
+            return Ok(());
+        }
+    }
+
     // 格式化输出
     formatter::print_result(
         &result,
@@ -82,6 +311,45 @@ fn main() -> Result<()> {
         options.show_examples,
         elapsed,
     );
+    known::annotate(&service, &known, &result)?;
+    if cli.pronounce {
+        pronounce::pronounce(service.online(), &config, &result);
+    }
 
     Ok(())
 }
+
+/// 仅使用本地词库的查询服务（搜索类子命令）
+fn local_service(cli: &Cli) -> Result<DictionaryService> {
+    if !dicts::any_installed() {
+        anyhow::bail!(tr!("common.no_dict"));
+    }
+    Ok(DictionaryService::new(
+        dicts::open_selected(&cli.dicts)?,
+        None,
+    ))
+}
+
+/// 在线词典，`--online-budget` 限制本次运行的请求数；被限流后的冷却记录在缓存目录中
+fn online_dictionary(cli: &Cli) -> OnlineDictionary {
+    let mut online = OnlineDictionary::new();
+    if let Ok(dir) = setup::cache_dir() {
+        online = online.with_cooldown_file(dir.join("online-cooldown"));
+    }
+    match cli.online_budget {
+        Some(budget) => online.with_request_budget(budget),
+        None => online,
+    }
+}
+
+/// 由命令行参数与配置文件确定查询选项
+fn lookup_options(cli: &Cli, config: &Config) -> LookupOptions {
+    let mut options = LookupOptions::default();
+    // 在线模式默认显示英文定义（因为在线API无中文翻译）
+    options.show_english = cli.show_english || cli.force_online;
+    options.show_examples = cli.show_examples;
+    options.force_online = cli.force_online;
+    options.max_examples = cli.max_examples;
+    options.auto_correct = (cli.auto_correct || config.auto_correct) && !cli.no_auto_correct;
+    options
+}
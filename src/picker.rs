@@ -0,0 +1,89 @@
+use anyhow::Result;
+use colored::Colorize;
+use console::{Key, Term};
+use std::io::IsTerminal;
+
+use lango::tr;
+
+/// 是否可以进行交互式选择（标准输入与输出都是终端）
+pub fn is_interactive() -> bool {
+    std::io::stdin().is_terminal() && Term::stdout().is_term()
+}
+
+/// 交互式选择拼写建议
+///
+/// 方向键（或 j/k）移动，数字键直接选择，Enter 确认，Esc / q 取消。
+/// 返回选中的单词，取消时返回 None。
+pub fn pick_suggestion(query: &str, suggestions: &[String]) -> Result<Option<String>> {
+    if suggestions.is_empty() {
+        return Ok(None);
+    }
+
+    let term = Term::stdout();
+    term.write_line("")?;
+    term.write_line(&format!(
+        "  {} {}",
+        "✗".red(),
+        tr!("lookup.not_found", query.yellow())
+    ))?;
+    term.write_line("")?;
+    term.write_line(&format!(
+        "  {} {}",
+        tr!("lookup.did_you_mean").bright_white(),
+        tr!("lookup.picker_hint").dimmed()
+    ))?;
+
+    term.hide_cursor()?;
+    let result = select_loop(&term, suggestions);
+    term.show_cursor()?;
+
+    // 清除列表与标题，随后由调用方输出选中的词条
+    term.clear_last_lines(suggestions.len() + 4)?;
+    result
+}
+
+fn select_loop(term: &Term, suggestions: &[String]) -> Result<Option<String>> {
+    let mut selected = 0;
+    render(term, suggestions, selected)?;
+
+    loop {
+        let choice = match term.read_key()? {
+            Key::ArrowUp | Key::Char('k') => {
+                selected = selected.checked_sub(1).unwrap_or(suggestions.len() - 1);
+                None
+            }
+            Key::ArrowDown | Key::Char('j') | Key::Tab => {
+                selected = (selected + 1) % suggestions.len();
+                None
+            }
+            Key::Enter => Some(Some(selected)),
+            Key::Escape | Key::Char('q') => Some(None),
+            Key::Char(c) => match c.to_digit(10) {
+                Some(n) if n >= 1 && (n as usize) <= suggestions.len() => {
+                    Some(Some(n as usize - 1))
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+
+        if let Some(choice) = choice {
+            return Ok(choice.map(|i| suggestions[i].clone()));
+        }
+
+        term.clear_last_lines(suggestions.len())?;
+        render(term, suggestions, selected)?;
+    }
+}
+
+fn render(term: &Term, suggestions: &[String], selected: usize) -> Result<()> {
+    for (i, s) in suggestions.iter().enumerate() {
+        let line = if i == selected {
+            format!("    {} {}. {}", "→".cyan(), i + 1, s.bold().bright_blue())
+        } else {
+            format!("      {}. {}", i + 1, s)
+        };
+        term.write_line(&line)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,210 @@
+use anyhow::{Context, Result};
+use colored::Colorize;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use lango::dict::online::OnlineDictionary;
+use lango::tr;
+use lango::types::{DictionaryEntry, LookupResult};
+
+use crate::config::Config;
+use crate::setup;
+
+/// 未配置播放器时依次尝试的命令（音频文件路径追加在末尾）
+const PLAYERS: &[&[&str]] = &[
+    &["mpv", "--no-video", "--really-quiet"],
+    &["ffplay", "-nodisp", "-autoexit", "-loglevel", "quiet"],
+    &["mpg123", "-q"],
+    &["afplay"],
+    &["cvlc", "--play-and-exit", "--quiet"],
+];
+
+/// 离线朗读命令（单词追加在末尾）
+const TTS_ENGINES: &[&[&str]] = &[&["espeak-ng"], &["espeak"], &["say"]];
+
+/// 播放查询结果中单词的发音
+///
+/// 优先使用缓存的音频，其次从 Free Dictionary API 下载真人发音，
+/// 都没有时用 espeak 离线朗读。发音失败只输出提示，不影响查询结果。
+/// `online` 为查询所用的在线词典，下载音频时共用其请求额度。
+pub fn pronounce(online: Option<&OnlineDictionary>, config: &Config, result: &LookupResult) {
+    if let LookupResult::Found(entry) | LookupResult::Corrected(entry) = result {
+        pronounce_entry(online, config, entry);
+    }
+}
+
+/// 播放词条中单词的发音（`--all` 时取第一个查到的词条）
+pub fn pronounce_entry(
+    online: Option<&OnlineDictionary>,
+    config: &Config,
+    entry: &DictionaryEntry,
+) {
+    if let Err(e) = play_word(online, config, &entry.word) {
+        println!("  {} {}", "✗".red(), e.to_string().dimmed());
+        println!();
+    }
+}
+
+fn play_word(online: Option<&OnlineDictionary>, config: &Config, word: &str) -> Result<()> {
+    if let Some(audio) = cached_audio(online, word)? {
+        return play_file(config.player.as_deref(), &audio);
+    }
+    if speak(word)? {
+        return Ok(());
+    }
+    anyhow::bail!(tr!("pronounce.unavailable", word))
+}
+
+/// 返回单词的本地音频文件，未缓存时尝试下载
+fn cached_audio(online: Option<&OnlineDictionary>, word: &str) -> Result<Option<PathBuf>> {
+    let path = audio_path(word)?;
+    if path.exists() {
+        return Ok(Some(path));
+    }
+    let Some(online) = online else {
+        return Ok(None);
+    };
+
+    // 额度用尽或被限流时同样视为没有音频，改用离线朗读
+    for url in online.audio_urls(word).unwrap_or_default() {
+        // 单个地址失效时尝试下一个
+        if download(online, &url, &path).is_ok() {
+            return Ok(Some(path));
+        }
+    }
+    Ok(None)
+}
+
+/// 音频缓存路径：`<缓存目录>/audio/<单词>.mp3`
+fn audio_path(word: &str) -> Result<PathBuf> {
+    Ok(setup::cache_dir()?
+        .join("audio")
+        .join(format!("{}.mp3", audio_file_stem(word))))
+}
+
+/// 缓存文件名：小写后保留字母、数字与 `-`，其余字节按 `%XX` 编码，
+/// 不同的单词（如 `co-op` 与 `co op`）不会共用同一个文件
+fn audio_file_stem(word: &str) -> String {
+    let mut name = String::new();
+    for byte in word.to_lowercase().bytes() {
+        if byte.is_ascii_alphanumeric() || byte == b'-' {
+            name.push(byte as char);
+        } else {
+            name.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    name
+}
+
+/// 下载到临时文件，完整后才放入缓存，避免中断留下不完整的音频
+fn download(online: &OnlineDictionary, url: &str, dest: &Path) -> Result<()> {
+    let bytes = online.download_audio(url)?;
+    if bytes.is_empty() {
+        anyhow::bail!(tr!("pronounce.download_failed", url));
+    }
+
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let partial = dest.with_extension("mp3.part");
+    fs::write(&partial, &bytes).with_context(|| tr!("pronounce.download_failed", url))?;
+    fs::rename(&partial, dest)?;
+    Ok(())
+}
+
+/// 用配置的播放器播放音频，未配置时依次尝试常见播放器
+///
+/// 配置的命令中 `{}` 替换为文件路径，没有 `{}` 时路径追加在末尾。
+fn play_file(player: Option<&str>, path: &Path) -> Result<()> {
+    let path = path.to_string_lossy();
+    if let Some(player) = player {
+        let mut args: Vec<String> = player.split_whitespace().map(str::to_string).collect();
+        if args.is_empty() {
+            anyhow::bail!(tr!("pronounce.no_player"));
+        }
+        if args.iter().any(|a| a.contains("{}")) {
+            for arg in &mut args {
+                *arg = arg.replace("{}", &path);
+            }
+        } else {
+            args.push(path.to_string());
+        }
+        let program = args.remove(0);
+        return match run_quiet(&program, &args)? {
+            Some(true) => Ok(()),
+            Some(false) => anyhow::bail!(tr!("pronounce.play_failed", program)),
+            None => anyhow::bail!(tr!("pronounce.player_not_found", program)),
+        };
+    }
+
+    for command in PLAYERS {
+        let mut args: Vec<String> = command[1..].iter().map(|a| a.to_string()).collect();
+        args.push(path.to_string());
+        match run_quiet(command[0], &args)? {
+            Some(true) => return Ok(()),
+            Some(false) => anyhow::bail!(tr!("pronounce.play_failed", command[0])),
+            None => continue,
+        }
+    }
+    anyhow::bail!(tr!("pronounce.no_player"))
+}
+
+/// 离线朗读单词，没有可用的朗读引擎时返回 `false`
+fn speak(word: &str) -> Result<bool> {
+    for command in TTS_ENGINES {
+        if let Some(success) = run_quiet(command[0], &tts_args(command, word))? {
+            return Ok(success);
+        }
+    }
+    Ok(false)
+}
+
+/// 朗读命令的参数，单词放在 `--` 之后，以 `-` 开头时也不会被当作选项
+fn tts_args(command: &[&str], word: &str) -> Vec<String> {
+    let mut args: Vec<String> = command[1..].iter().map(|a| a.to_string()).collect();
+    args.push("--".to_string());
+    args.push(word.to_string());
+    args
+}
+
+/// 运行命令并等待结束，丢弃其输出。命令不存在时返回 `None`
+fn run_quiet(program: &str, args: &[String]) -> Result<Option<bool>> {
+    let status = Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+    match status {
+        Ok(status) => Ok(Some(status.success())),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e).with_context(|| tr!("pronounce.play_failed", program)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn audio_file_stem_is_distinct_per_word() {
+        assert_eq!(audio_file_stem("Hello"), "hello");
+        assert_eq!(audio_file_stem("co-op"), "co-op");
+        assert_eq!(audio_file_stem("co op"), "co%20op");
+        assert_eq!(audio_file_stem("co_op"), "co%5Fop");
+        assert_eq!(audio_file_stem("co%20op"), "co%2520op");
+        assert_eq!(audio_file_stem("café"), "caf%C3%A9");
+        assert_eq!(audio_file_stem("../x"), "%2E%2E%2Fx");
+    }
+
+    #[test]
+    fn tts_args_end_options_before_the_word() {
+        assert_eq!(tts_args(&["espeak-ng"], "-v"), ["--", "-v"]);
+        assert_eq!(
+            tts_args(&["say", "-r", "180"], "hello"),
+            ["-r", "180", "--", "hello"]
+        );
+    }
+}
@@ -0,0 +1,535 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Local};
+use colored::Colorize;
+use console::{Key, Term};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+
+use lango::dict::DictionaryService;
+use lango::formatter::truncate;
+use lango::tr;
+use lango::types::{WordFilter, WordInfo};
+
+use crate::cli::QuizMode;
+use crate::report::{item, percent, ratio, section};
+use crate::text::brief_translation;
+use crate::{picker, setup};
+
+/// 每道选择题的选项数
+const CHOICES: usize = 4;
+/// 干扰项从词频最接近的若干词中随机选取
+const DISTRACTOR_POOL: usize = 12;
+/// 统计中列出的最常答错的单词数
+const MOST_MISSED: usize = 10;
+/// 拼写题中输入此命令退出测验
+const QUIT_COMMAND: &str = ":q";
+
+/// 一次作答记录，按行追加到 `quiz.jsonl`
+#[derive(Debug, Serialize, Deserialize)]
+struct QuizRecord {
+    /// 作答时间（RFC 3339）
+    time: String,
+    /// 所属测验的开始时间，用于区分不同测验
+    session: String,
+    mode: RecordMode,
+    word: String,
+    correct: bool,
+}
+
+/// 记录中的题型，与命令行参数分开定义，以免改动参数影响已有记录的读取
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum RecordMode {
+    Choice,
+    Spell,
+    Reverse,
+}
+
+impl From<QuizMode> for RecordMode {
+    fn from(mode: QuizMode) -> Self {
+        match mode {
+            QuizMode::Choice => Self::Choice,
+            QuizMode::Spell => Self::Spell,
+            QuizMode::Reverse => Self::Reverse,
+        }
+    }
+}
+
+/// 一道题的作答结果
+enum Outcome {
+    Answered(bool),
+    /// 跳过，不计入成绩与记录
+    Skipped,
+    Quit,
+}
+
+/// 测验记录文件路径
+pub fn history_path() -> Result<PathBuf> {
+    Ok(setup::data_dir()?.join("quiz.jsonl"))
+}
+
+fn load_history() -> Result<Vec<QuizRecord>> {
+    let path = history_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content =
+        fs::read_to_string(&path).with_context(|| tr!("text.read_failed", path.display()))?;
+    // 跳过损坏的行，而不是让整个记录文件失效
+    Ok(content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+fn append_history(record: &QuizRecord) -> Result<()> {
+    let path = history_path()?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| tr!("quiz.write_failed", path.display()))?;
+    writeln!(file, "{}", serde_json::to_string(record)?)?;
+    Ok(())
+}
+
+/// 进行一次测验
+///
+/// `mistakes` 为真时只从上次答错的单词中出题，否则从满足 `filter` 的单词池中随机出题。
+/// 每答一题立即写入记录，中途退出也会保留已作答的结果。
+pub fn run(
+    service: &DictionaryService,
+    filter: &WordFilter,
+    mode: QuizMode,
+    count: usize,
+    mistakes: bool,
+) -> Result<()> {
+    if !picker::is_interactive() {
+        anyhow::bail!(tr!("quiz.needs_terminal"));
+    }
+
+    let words = if mistakes {
+        missed_words(service, count)?
+    } else {
+        sample_words(service, filter, count)?
+    };
+    if words.is_empty() {
+        anyhow::bail!(if mistakes {
+            tr!("quiz.no_mistakes")
+        } else {
+            tr!("random.empty_pool")
+        });
+    }
+
+    let term = Term::stdout();
+    let session = Local::now().to_rfc3339();
+    let started = Local::now();
+    let mut answered = 0;
+    let mut correct = 0;
+
+    term.write_line("")?;
+    term.write_line(&format!("  {}", tr!("quiz.hint").dimmed()))?;
+
+    for (i, info) in words.iter().enumerate() {
+        term.write_line("")?;
+        let progress = format!("[{}/{}]", i + 1, words.len());
+        let result = match mode {
+            QuizMode::Choice | QuizMode::Reverse => {
+                ask_choice(&term, service, filter, info, mode, &progress)?
+            }
+            QuizMode::Spell => ask_spelling(&term, info, &progress)?,
+        };
+        let is_correct = match result {
+            Outcome::Answered(is_correct) => is_correct,
+            Outcome::Skipped => continue,
+            Outcome::Quit => break,
+        };
+
+        answered += 1;
+        if is_correct {
+            correct += 1;
+        }
+        append_history(&QuizRecord {
+            time: Local::now().to_rfc3339(),
+            session: session.clone(),
+            mode: mode.into(),
+            word: info.word.clone(),
+            correct: is_correct,
+        })?;
+    }
+
+    let minutes = (Local::now() - started).num_seconds() as f64 / 60.0;
+    term.write_line("")?;
+    term.write_line(&format!(
+        "  {} {}",
+        "──".dimmed(),
+        tr!(
+            "quiz.score",
+            correct,
+            answered,
+            percent(ratio(correct, answered)),
+            format!("{:.1}", minutes)
+        )
+        .bright_white()
+    ))?;
+    term.write_line("")?;
+    Ok(())
+}
+
+/// 从单词池中随机抽取不重复的单词，跳过变形词（其释义通常直接给出原形）
+fn sample_words(
+    service: &DictionaryService,
+    filter: &WordFilter,
+    count: usize,
+) -> Result<Vec<WordInfo>> {
+    let total = service.count_words(filter)?;
+    let mut seen = HashSet::new();
+    let mut words = Vec::new();
+    let mut attempts = 0;
+    while words.len() < count && attempts < count * 5 && total > 0 {
+        attempts += 1;
+        let Some(info) = service.nth_word(filter, fastrand::usize(..total))? else {
+            continue;
+        };
+        if info.lemma().is_some() || !seen.insert(info.word.to_lowercase()) {
+            continue;
+        }
+        words.push(info);
+    }
+    Ok(words)
+}
+
+/// 最近一次作答仍为错误的单词，最近答错的在前
+fn missed_words(service: &DictionaryService, count: usize) -> Result<Vec<WordInfo>> {
+    let mut latest: HashMap<String, (String, bool)> = HashMap::new();
+    for record in load_history()? {
+        latest.insert(record.word.to_lowercase(), (record.time, record.correct));
+    }
+    let mut missed: Vec<(String, String)> = latest
+        .into_iter()
+        .filter(|(_, (_, correct))| !correct)
+        .map(|(word, (time, _))| (time, word))
+        .collect();
+    missed.sort_by(|a, b| b.0.cmp(&a.0));
+
+    let mut words = Vec::new();
+    for (_, word) in missed.into_iter().take(count) {
+        if let Some(info) = service.word_info(&word)? {
+            words.push(info);
+        }
+    }
+    fastrand::shuffle(&mut words);
+    Ok(words)
+}
+
+/// 干扰项：词频相近、释义不同的单词，不足时用随机单词补足
+fn distractors(
+    service: &DictionaryService,
+    filter: &WordFilter,
+    answer: &WordInfo,
+) -> Result<Vec<WordInfo>> {
+    let mut candidates = match answer.rank() {
+        Some(rank) => service.nearest_by_rank(filter, rank, &answer.word, DISTRACTOR_POOL)?,
+        None => Vec::new(),
+    };
+    fastrand::shuffle(&mut candidates);
+    if candidates.len() < CHOICES {
+        candidates.extend(sample_words(service, filter, CHOICES * 2)?);
+    }
+
+    let mut translations = HashSet::from([brief_translation(answer)]);
+    let mut picked = Vec::new();
+    for info in candidates {
+        if picked.len() == CHOICES - 1 {
+            break;
+        }
+        if info.word.eq_ignore_ascii_case(&answer.word) || info.lemma().is_some() {
+            continue;
+        }
+        if translations.insert(brief_translation(&info)) {
+            picked.push(info);
+        }
+    }
+    Ok(picked)
+}
+
+/// 选择题：choice 模式看释义选单词，reverse 模式看单词选释义
+fn ask_choice(
+    term: &Term,
+    service: &DictionaryService,
+    filter: &WordFilter,
+    answer: &WordInfo,
+    mode: QuizMode,
+    progress: &str,
+) -> Result<Outcome> {
+    let mut options = distractors(service, filter, answer)?;
+    options.push(answer.clone());
+    fastrand::shuffle(&mut options);
+    let correct_index = options
+        .iter()
+        .position(|o| o.word == answer.word)
+        .unwrap_or(0);
+
+    let reverse = matches!(mode, QuizMode::Reverse);
+    let prompt = if reverse {
+        format!(
+            "{}{}",
+            answer.word.bright_blue().bold(),
+            phonetic(answer).cyan()
+        )
+    } else {
+        mask(&brief_translation(answer), &answer.word)
+            .green()
+            .to_string()
+    };
+    term.write_line(&format!("  {} {}", progress.dimmed(), prompt))?;
+    for (i, option) in options.iter().enumerate() {
+        let text = if reverse {
+            truncate(&brief_translation(option), 40)
+        } else {
+            option.word.clone()
+        };
+        term.write_line(&format!("    {} {}", format!("{}.", i + 1).dimmed(), text))?;
+    }
+
+    let choice = loop {
+        match term.read_key()? {
+            Key::Char('q') | Key::Escape => return Ok(Outcome::Quit),
+            Key::Char(c) => match c.to_digit(10) {
+                Some(n) if n >= 1 && (n as usize) <= options.len() => break n as usize - 1,
+                _ => {}
+            },
+            _ => {}
+        }
+    };
+
+    let is_correct = choice == correct_index;
+    let reveal = if reverse {
+        truncate(&brief_translation(answer), 40)
+    } else {
+        format!("{}{}", answer.word, phonetic(answer))
+    };
+    print_verdict(term, is_correct, &reveal)?;
+    Ok(Outcome::Answered(is_correct))
+}
+
+/// 拼写题：看释义与首字母提示写出单词。空行跳过（显示答案但不记录），`:q` 退出
+fn ask_spelling(term: &Term, answer: &WordInfo, progress: &str) -> Result<Outcome> {
+    let translation = mask(&brief_translation(answer), &answer.word);
+    term.write_line(&format!("  {} {}", progress.dimmed(), translation.green()))?;
+
+    let mut hint = String::new();
+    for (i, c) in answer.word.chars().enumerate() {
+        if i > 0 {
+            hint.push(' ');
+        }
+        hint.push(if i == 0 { c } else { '_' });
+    }
+    term.write_str(&format!(
+        "    {}  {} ",
+        hint.bright_white(),
+        tr!("quiz.letters", answer.word.chars().count()).dimmed()
+    ))?;
+    let input = term.read_line()?;
+    let input = input.trim();
+    let reveal = format!("{}{}", answer.word, phonetic(answer));
+
+    if input == QUIT_COMMAND {
+        return Ok(Outcome::Quit);
+    }
+    if input.is_empty() {
+        term.write_line(&format!(
+            "    {} {}",
+            "→".dimmed(),
+            tr!("quiz.skipped", reveal.bright_blue())
+        ))?;
+        return Ok(Outcome::Skipped);
+    }
+
+    let is_correct = input.eq_ignore_ascii_case(&answer.word);
+    print_verdict(term, is_correct, &reveal)?;
+    Ok(Outcome::Answered(is_correct))
+}
+
+fn print_verdict(term: &Term, is_correct: bool, answer: &str) -> Result<()> {
+    if is_correct {
+        term.write_line(&format!("    {} {}", "✓".green(), answer.dimmed()))?;
+    } else {
+        term.write_line(&format!(
+            "    {} {}",
+            "✗".red(),
+            tr!("quiz.answer", answer.bright_blue())
+        ))?;
+    }
+    Ok(())
+}
+
+/// 输出历史测验统计
+pub fn print_stats() -> Result<()> {
+    let history = load_history()?;
+    println!();
+    if history.is_empty() {
+        println!("  {}", tr!("quiz.no_history"));
+        println!();
+        return Ok(());
+    }
+
+    let correct = history.iter().filter(|r| r.correct).count();
+    let sessions: HashSet<&str> = history.iter().map(|r| r.session.as_str()).collect();
+    section(tr!("quiz.stats_overview"));
+    item(tr!("quiz.sessions"), &sessions.len().to_string());
+    item(
+        tr!("quiz.answered"),
+        &tr!(
+            "quiz.accuracy",
+            history.len(),
+            percent(ratio(correct, history.len()))
+        ),
+    );
+
+    let week_ago = Local::now() - Duration::days(7);
+    let recent: Vec<&QuizRecord> = history
+        .iter()
+        .filter(|r| DateTime::parse_from_rfc3339(&r.time).is_ok_and(|t| t >= week_ago))
+        .collect();
+    let recent_correct = recent.iter().filter(|r| r.correct).count();
+    item(
+        tr!("quiz.last_week"),
+        &tr!(
+            "quiz.accuracy",
+            recent.len(),
+            percent(ratio(recent_correct, recent.len()))
+        ),
+    );
+    println!();
+
+    section(tr!("quiz.by_mode"));
+    for mode in [RecordMode::Choice, RecordMode::Spell, RecordMode::Reverse] {
+        let records: Vec<&QuizRecord> = history.iter().filter(|r| r.mode == mode).collect();
+        if records.is_empty() {
+            continue;
+        }
+        let correct = records.iter().filter(|r| r.correct).count();
+        item(
+            mode_name(mode),
+            &tr!(
+                "quiz.accuracy",
+                records.len(),
+                percent(ratio(correct, records.len()))
+            ),
+        );
+    }
+    println!();
+
+    // 答错次数最多的单词
+    let mut tally: HashMap<&str, (usize, usize)> = HashMap::new();
+    for record in &history {
+        let entry = tally.entry(record.word.as_str()).or_default();
+        entry.1 += 1;
+        if !record.correct {
+            entry.0 += 1;
+        }
+    }
+    let mut missed: Vec<(&str, (usize, usize))> = tally
+        .into_iter()
+        .filter(|(_, (misses, _))| *misses > 0)
+        .collect();
+    missed.sort_by(|a, b| b.1.0.cmp(&a.1.0).then_with(|| a.0.cmp(b.0)));
+    if !missed.is_empty() {
+        section(tr!("quiz.most_missed"));
+        missed.truncate(MOST_MISSED);
+        let width = missed
+            .iter()
+            .map(|(word, _)| word.chars().count())
+            .max()
+            .unwrap_or(0);
+        for (word, (misses, total)) in missed {
+            println!(
+                "    {}{}  {}",
+                word.bright_blue().bold(),
+                " ".repeat(width - word.chars().count()),
+                tr!("quiz.missed_count", misses, total).dimmed()
+            );
+        }
+        println!();
+        println!("  {}", tr!("quiz.review_hint").dimmed());
+        println!();
+    }
+    Ok(())
+}
+
+fn mode_name(mode: RecordMode) -> &'static str {
+    match mode {
+        RecordMode::Choice => tr!("quiz.mode_choice"),
+        RecordMode::Spell => tr!("quiz.mode_spell"),
+        RecordMode::Reverse => tr!("quiz.mode_reverse"),
+    }
+}
+
+/// 遮盖释义中作为独立单词出现的答案本身（如“go的过去式”），不遮盖更长单词中的部分
+fn mask(text: &str, word: &str) -> String {
+    if word.is_empty() {
+        return text.to_string();
+    }
+    // ASCII 小写不改变字节长度，因此可以直接用匹配位置切分原文
+    let lower = text.to_ascii_lowercase();
+    let needle = word.to_ascii_lowercase();
+    let is_letter = |c: Option<char>| c.is_some_and(|c| c.is_ascii_alphabetic());
+    let mut out = String::new();
+    let mut last = 0;
+    for (start, _) in lower.match_indices(&needle) {
+        let end = start + needle.len();
+        if is_letter(lower[..start].chars().next_back()) || is_letter(lower[end..].chars().next()) {
+            continue;
+        }
+        out.push_str(&text[last..start]);
+        out.push_str("___");
+        last = end;
+    }
+    out.push_str(&text[last..]);
+    out
+}
+
+fn phonetic(info: &WordInfo) -> String {
+    info.phonetic
+        .as_deref()
+        .filter(|p| !p.is_empty())
+        .map(|p| format!(" /{}/", p.trim_matches('/')))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mask_hides_the_answer_case_insensitively() {
+        assert_eq!(mask("Go的过去式", "go"), "___的过去式");
+        assert_eq!(mask("n. 猫", "cat"), "n. 猫");
+        assert_eq!(mask("abc", ""), "abc");
+    }
+
+    #[test]
+    fn mask_keeps_the_answer_inside_longer_words() {
+        assert_eq!(
+            mask("n. 猫; cat's category", "cat"),
+            "n. 猫; ___'s category"
+        );
+        assert_eq!(mask("Category, cat.", "cat"), "Category, ___.");
+        assert_eq!(mask("scatter", "cat"), "scatter");
+    }
+
+    #[test]
+    fn record_mode_keeps_lowercase_names() {
+        let json = serde_json::to_string(&RecordMode::from(QuizMode::Reverse)).unwrap();
+        assert_eq!(json, "\"reverse\"");
+        let mode: RecordMode = serde_json::from_str("\"spell\"").unwrap();
+        assert_eq!(mode, RecordMode::Spell);
+    }
+}
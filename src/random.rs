@@ -0,0 +1,79 @@
+use anyhow::Result;
+use chrono::{Datelike, Local, NaiveDate};
+use colored::Colorize;
+use std::time::Instant;
+
+use lango::dict::DictionaryService;
+use lango::formatter;
+use lango::tr;
+use lango::types::{LookupOptions, WordFilter};
+
+use crate::known::{self, KnownWords};
+
+/// 从单词池中随机抽取一个词并输出词条
+pub fn random(
+    service: &DictionaryService,
+    filter: &WordFilter,
+    options: &LookupOptions,
+) -> Result<()> {
+    let count = pool_size(service, filter)?;
+    let index = fastrand::usize(..count);
+    print_word(service, filter, index, options)
+}
+
+/// 每日一词：同一天、同一筛选条件下总是同一个词
+pub fn daily(
+    service: &DictionaryService,
+    filter: &WordFilter,
+    options: &LookupOptions,
+) -> Result<()> {
+    let today = Local::now().date_naive();
+    let count = pool_size(service, filter)?;
+    let index = (day_seed(today) % count as u64) as usize;
+
+    println!();
+    println!(
+        "  {} {}",
+        "☀".yellow(),
+        tr!("random.daily", today.format("%Y-%m-%d")).bright_white()
+    );
+    print_word(service, filter, index, options)
+}
+
+fn pool_size(service: &DictionaryService, filter: &WordFilter) -> Result<usize> {
+    let count = service.count_words(filter)?;
+    if count == 0 {
+        anyhow::bail!(tr!("random.empty_pool"));
+    }
+    Ok(count)
+}
+
+fn print_word(
+    service: &DictionaryService,
+    filter: &WordFilter,
+    index: usize,
+    options: &LookupOptions,
+) -> Result<()> {
+    let Some(info) = service.nth_word(filter, index)? else {
+        anyhow::bail!(tr!("random.empty_pool"));
+    };
+
+    let start = Instant::now();
+    let result = service.lookup(&info.word, options)?;
+    formatter::print_result(
+        &result,
+        &info.word,
+        options.show_english,
+        options.show_examples,
+        start.elapsed(),
+    );
+    known::annotate(service, &KnownWords::load()?, &result)
+}
+
+/// 由日期得到分布均匀的种子，使相邻日期的选词相互独立（SplitMix64）
+fn day_seed(date: NaiveDate) -> u64 {
+    let mut z = (date.num_days_from_ce() as u64).wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
@@ -0,0 +1,58 @@
+use colored::Colorize;
+
+/// 报告中标签列的显示宽度
+const LABEL_WIDTH: usize = 14;
+
+/// 输出小节标题
+pub fn section(title: &str) {
+    println!("  {}", title.bright_white().underline());
+}
+
+/// 输出一行 `标签: 值`，值按标签列宽对齐
+pub fn item(label: &str, value: &str) {
+    let padding = " ".repeat(LABEL_WIDTH.saturating_sub(display_width(label)));
+    println!(
+        "    {}{} {}",
+        format!("{}:", label).dimmed(),
+        padding,
+        value
+    );
+}
+
+/// 显示宽度：中文字符按两列计算
+pub fn display_width(s: &str) -> usize {
+    s.chars().map(|c| if c.is_ascii() { 1 } else { 2 }).sum()
+}
+
+/// 所占比例，总数为 0 时为 0
+pub fn ratio(part: usize, total: usize) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        part as f64 / total as f64
+    }
+}
+
+/// 比例格式化为百分数，保留一位小数
+pub fn percent(value: f64) -> String {
+    format!("{:.1}%", value * 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_width_counts_cjk_as_two_columns() {
+        assert_eq!(display_width("words"), 5);
+        assert_eq!(display_width("词库"), 4);
+        assert_eq!(display_width("CET4 词汇"), 9);
+    }
+
+    #[test]
+    fn percent_of_ratio() {
+        assert_eq!(percent(ratio(1, 3)), "33.3%");
+        assert_eq!(percent(ratio(5, 5)), "100.0%");
+        assert_eq!(percent(ratio(0, 0)), "0.0%");
+    }
+}
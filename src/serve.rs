@@ -0,0 +1,262 @@
+use anyhow::Result;
+use colored::Colorize;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use lango::dict::DictionaryService;
+use lango::tr;
+use lango::types::{LookupOptions, LookupResult, WordPattern};
+
+/// `/suggest` 的默认结果数
+const DEFAULT_SUGGEST_LIMIT: usize = 5;
+/// `/search` 的默认每页结果数
+const DEFAULT_SEARCH_LIMIT: usize = 20;
+/// `limit` 参数上限，避免单个请求取出大量结果
+const MAX_LIMIT: usize = 200;
+
+/// 接口错误：HTTP 状态码与错误信息
+struct ApiError {
+    status: u16,
+    message: String,
+}
+
+impl ApiError {
+    fn bad_request(message: String) -> Self {
+        Self {
+            status: 400,
+            message,
+        }
+    }
+}
+
+impl From<anyhow::Error> for ApiError {
+    fn from(e: anyhow::Error) -> Self {
+        Self {
+            status: 500,
+            message: e.to_string(),
+        }
+    }
+}
+
+type Params = HashMap<String, String>;
+
+/// 启动 HTTP 服务并持续处理请求
+///
+/// 词库连接与 SQLite 页缓存在整个进程中复用；请求按顺序处理，
+/// 因为 SQLite 连接不能跨线程共享。`defaults` 为未指定参数时的查询选项。
+///
+/// 默认不发送 CORS 头，浏览器中的任意网页都无法调用本服务（在线查询会消耗请求额度）；
+/// `cors` 为允许跨域访问的来源，如 `chrome-extension://<id>` 或 `*`。
+pub fn run(
+    service: DictionaryService,
+    defaults: LookupOptions,
+    host: &str,
+    port: u16,
+    cors: Option<&str>,
+) -> Result<()> {
+    let addr = format!("{}:{}", host, port);
+    let server =
+        Server::http(&addr).map_err(|e| anyhow::anyhow!(tr!("serve.bind_failed", addr, e)))?;
+
+    println!();
+    println!("  {}", tr!("serve.listening", addr).bright_blue());
+    println!("  {}", "GET /lookup?q=  /suggest?q=  /search?q=".dimmed());
+    println!("  {}", tr!("serve.stop_hint").dimmed());
+    println!();
+
+    for request in server.incoming_requests() {
+        let (status, body) = handle(&service, &defaults, &request);
+        respond(request, status, body, cors);
+    }
+    Ok(())
+}
+
+fn handle(
+    service: &DictionaryService,
+    defaults: &LookupOptions,
+    request: &Request,
+) -> (u16, Option<Value>) {
+    match request.method() {
+        Method::Get => {}
+        // CORS 预检请求
+        Method::Options => return (204, None),
+        _ => return (405, Some(error_body(tr!("serve.method_not_allowed")))),
+    }
+
+    let url = request.url();
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let params: Params = url::form_urlencoded::parse(query.as_bytes())
+        .into_owned()
+        .collect();
+
+    let result = match path {
+        "/lookup" => lookup(service, defaults, &params),
+        "/suggest" => suggest(service, &params),
+        "/search" => search(service, &params),
+        _ => Err(ApiError {
+            status: 404,
+            message: tr!("serve.unknown_endpoint", path),
+        }),
+    };
+
+    match result {
+        Ok(body) => (200, Some(body)),
+        Err(e) => (e.status, Some(error_body(&e.message))),
+    }
+}
+
+/// `GET /lookup?q=<词>[&english=1][&examples=1][&online=1][&auto_correct=1]`
+fn lookup(
+    service: &DictionaryService,
+    defaults: &LookupOptions,
+    params: &Params,
+) -> Result<Value, ApiError> {
+    let q = query_param(params)?;
+    let mut options = defaults.clone();
+    options.show_english = bool_param(params, "english")?.unwrap_or(defaults.show_english);
+    options.show_examples = bool_param(params, "examples")?.unwrap_or(defaults.show_examples);
+    options.force_online = bool_param(params, "online")?.unwrap_or(defaults.force_online);
+    options.max_examples = usize_param(params, "num_examples")?.unwrap_or(defaults.max_examples);
+    options.auto_correct = bool_param(params, "auto_correct")?.unwrap_or(defaults.auto_correct);
+
+    let (status, entry, suggestions) = match service.lookup(q, &options)? {
+        LookupResult::Found(entry) => ("found", Some(entry), Vec::new()),
+        LookupResult::Corrected(entry) => ("corrected", Some(entry), Vec::new()),
+        LookupResult::Suggestions(words) => ("suggestions", None, words),
+        _ => ("not_found", None, Vec::new()),
+    };
+
+    Ok(json!({
+        "query": q,
+        "status": status,
+        "entry": entry,
+        "suggestions": suggestions,
+    }))
+}
+
+/// `GET /suggest?q=<词>[&limit=N]`
+fn suggest(service: &DictionaryService, params: &Params) -> Result<Value, ApiError> {
+    let q = query_param(params)?;
+    let limit = limit_param(params, DEFAULT_SUGGEST_LIMIT)?;
+    let words: Vec<String> = service
+        .suggest(&q.to_lowercase(), limit)?
+        .into_iter()
+        .map(|s| s.word)
+        .collect();
+
+    Ok(json!({
+        "query": q,
+        "suggestions": words,
+    }))
+}
+
+/// `GET /search?q=<模式>[&mode=glob|regex|phrase][&len=N][&page=N][&limit=N][&any_order=1]`
+fn search(service: &DictionaryService, params: &Params) -> Result<Value, ApiError> {
+    let q = query_param(params)?;
+    let limit = limit_param(params, DEFAULT_SEARCH_LIMIT)?;
+    let page = usize_param(params, "page")?.unwrap_or(1).max(1);
+    // SQLite 的 OFFSET 为 i64，超出范围的页码同样视为无效
+    let offset = (page - 1)
+        .checked_mul(limit)
+        .filter(|offset| i64::try_from(*offset).is_ok())
+        .ok_or_else(|| ApiError::bad_request(tr!("serve.invalid_param", "page", page)))?;
+    let mode = params.get("mode").map(String::as_str).unwrap_or("glob");
+
+    let (total, hits) = match mode {
+        "glob" | "regex" => {
+            let pattern = if mode == "regex" {
+                regex::Regex::new(q).map_err(|e| ApiError::bad_request(e.to_string()))?;
+                WordPattern::Regex(q.to_string())
+            } else {
+                WordPattern::Glob(q.to_string())
+            };
+            let len = usize_param(params, "len")?;
+            service.match_words(&pattern, len, offset, limit)?
+        }
+        "phrase" => {
+            // 词组搜索在内存中逐条匹配，取出全部结果后分页
+            let words: Vec<String> = q.split_whitespace().map(str::to_string).collect();
+            let any_order = bool_param(params, "any_order")?.unwrap_or(false);
+            let hits = service.search_phrases(&words, any_order, usize::MAX)?;
+            let total = hits.len();
+            (total, hits.into_iter().skip(offset).take(limit).collect())
+        }
+        _ => {
+            return Err(ApiError::bad_request(tr!(
+                "serve.invalid_param",
+                "mode",
+                mode
+            )));
+        }
+    };
+
+    Ok(json!({
+        "query": q,
+        "mode": mode,
+        "total": total,
+        "page": page,
+        "limit": limit,
+        "hits": hits,
+    }))
+}
+
+fn query_param(params: &Params) -> Result<&str, ApiError> {
+    params
+        .get("q")
+        .map(|q| q.trim())
+        .filter(|q| !q.is_empty())
+        .ok_or_else(|| ApiError::bad_request(tr!("serve.missing_query").to_string()))
+}
+
+fn bool_param(params: &Params, name: &str) -> Result<Option<bool>, ApiError> {
+    match params.get(name).map(String::as_str) {
+        None => Ok(None),
+        Some("1" | "true" | "yes") => Ok(Some(true)),
+        Some("0" | "false" | "no") => Ok(Some(false)),
+        Some(other) => Err(ApiError::bad_request(tr!(
+            "serve.invalid_param",
+            name,
+            other
+        ))),
+    }
+}
+
+/// `limit` 参数，超过 `MAX_LIMIT` 时取上限
+fn limit_param(params: &Params, default: usize) -> Result<usize, ApiError> {
+    Ok(usize_param(params, "limit")?
+        .unwrap_or(default)
+        .min(MAX_LIMIT))
+}
+
+fn usize_param(params: &Params, name: &str) -> Result<Option<usize>, ApiError> {
+    params
+        .get(name)
+        .map(|v| {
+            v.parse()
+                .map_err(|_| ApiError::bad_request(tr!("serve.invalid_param", name, v)))
+        })
+        .transpose()
+}
+
+fn error_body(message: &str) -> Value {
+    json!({ "error": message })
+}
+
+fn respond(request: Request, status: u16, body: Option<Value>, cors: Option<&str>) {
+    let data = body.map(|b| b.to_string()).unwrap_or_default();
+    let mut response = Response::from_string(data).with_status_code(status);
+    let mut headers = vec![("Content-Type", "application/json; charset=utf-8")];
+    // 仅在显式允许时才让浏览器扩展与网页跨域调用
+    if let Some(origin) = cors {
+        headers.push(("Access-Control-Allow-Origin", origin));
+        headers.push(("Access-Control-Allow-Methods", "GET, OPTIONS"));
+    }
+    for (name, value) in headers {
+        if let Ok(header) = Header::from_bytes(name, value) {
+            response.add_header(header);
+        }
+    }
+    // 客户端提前断开时忽略写入错误
+    let _ = request.respond(response);
+}
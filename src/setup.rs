@@ -1,14 +1,33 @@
 use anyhow::{Context, Result};
+use colored::Colorize;
 use indicatif::{ProgressBar, ProgressStyle};
+use serde::{Deserialize, Serialize};
 use std::fs;
 use std::io::{self, Read, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use lango::dict::ecdict::EcdictDictionary;
+use lango::tr;
+
+use crate::dicts;
+
+/// ECDICT 最新发布信息（GitHub Releases API）
+const RELEASE_MANIFEST_URL: &str =
+    "https://api.github.com/repos/skywind3000/ECDICT/releases/latest";
+
+/// 首次安装使用的 ECDICT 版本，下载地址固定在源码中，不依赖 GitHub API
+const DEFAULT_VERSION: &str = "1.0.28";
+const DEFAULT_URL: &str =
+    "https://github.com/skywind3000/ECDICT/releases/download/1.0.28/ecdict-sqlite-28.zip";
+/// 默认版本压缩包的 SHA-256（十六进制）；为 None 时跳过比对，安装后输出并记录实际摘要
+const DEFAULT_SHA256: Option<&str> = None;
 
 /// 获取词库存储目录
 pub fn data_dir() -> Result<PathBuf> {
     let base = dirs::data_dir()
         .or_else(|| dirs::home_dir().map(|h| h.join(".local").join("share")))
-        .context("无法获取用户数据目录")?;
+        .context(tr!("setup.no_data_dir"))?;
     Ok(base.join("lango"))
 }
 
@@ -17,9 +36,57 @@ pub fn db_path() -> Result<PathBuf> {
     Ok(data_dir()?.join("stardict.db"))
 }
 
-/// 检查词库是否存在
-pub fn is_db_installed() -> bool {
-    db_path().map(|p| p.exists()).unwrap_or(false)
+/// 获取配置文件路径
+pub fn config_path() -> Result<PathBuf> {
+    let base = dirs::config_dir()
+        .or_else(|| dirs::home_dir().map(|h| h.join(".config")))
+        .context(tr!("setup.no_config_dir"))?;
+    Ok(base.join("lango").join("config.toml"))
+}
+
+/// 获取缓存目录
+pub fn cache_dir() -> Result<PathBuf> {
+    let base = dirs::cache_dir()
+        .or_else(|| dirs::home_dir().map(|h| h.join(".cache")))
+        .context(tr!("setup.no_cache_dir"))?;
+    Ok(base.join("lango"))
+}
+
+/// 获取词库元数据文件路径（与词库同名，如 `stardict.meta.json`）
+pub fn meta_path(db: &Path) -> PathBuf {
+    db.with_extension("meta.json")
+}
+
+/// 已安装词库的元数据
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DbMeta {
+    /// ECDICT 版本号，从本地文件导入时未知
+    pub version: Option<String>,
+    /// 下载来源 URL 或导入的文件路径
+    pub source: String,
+    /// 压缩包 SHA-256（仅下载安装时记录）
+    pub sha256: Option<String>,
+    /// 安装时间（Unix 时间戳，秒）
+    pub installed_at: u64,
+}
+
+/// 读取已安装词库的元数据
+pub fn read_meta(db: &Path) -> Option<DbMeta> {
+    let data = fs::read_to_string(meta_path(db)).ok()?;
+    serde_json::from_str(&data).ok()
+}
+
+fn write_meta(db: &Path, meta: &DbMeta) -> Result<()> {
+    let path = meta_path(db);
+    fs::write(&path, serde_json::to_string_pretty(meta)?)
+        .with_context(|| tr!("setup.write_meta_failed", path.display()))
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
 }
 
 /// 交互式引导下载词库
@@ -27,10 +94,10 @@ pub fn interactive_setup() -> Result<PathBuf> {
     let path = db_path()?;
 
     println!();
-    println!("  Lango 需要 ECDICT 离线词库 (770,000+ 词条)");
-    println!("  首次使用需要下载约 180MB 数据");
+    println!("  {}", tr!("setup.intro"));
+    println!("  {}", tr!("setup.intro_size"));
     println!();
-    print!("  是否现在下载? [Y/n]: ");
+    print!("  {}", tr!("setup.confirm"));
     io::stdout().flush()?;
 
     let mut input = String::new();
@@ -39,21 +106,137 @@ pub fn interactive_setup() -> Result<PathBuf> {
 
     if input == "n" || input == "no" {
         println!();
-        println!("  你可以稍后运行 `lango setup` 下载词库");
-        println!("  或手动下载后执行 `lango setup --import <路径>`");
-        anyhow::bail!("用户取消下载");
+        println!("  {}", tr!("setup.later"));
+        println!("  {}", tr!("setup.later_import"));
+        anyhow::bail!(tr!("setup.cancelled"));
     }
 
-    download_db(&path)?;
+    download_db(&path, &default_release())?;
     Ok(path)
 }
 
-/// 从本地文件导入词库
-pub fn import_db(source: &PathBuf) -> Result<PathBuf> {
-    let dest = db_path()?;
+/// 检查词库更新，返回更新的版本（若有）
+///
+/// 已安装词库的版本未知（早期安装或手动导入）时不视为可更新，以免覆盖导入的词库。
+pub fn check_update() -> Result<Option<DbRelease>> {
+    let path = db_path()?;
+    let installed = read_meta(&path).and_then(|m| m.version);
+    let latest = fetch_latest_release()?;
+
+    println!();
+    println!(
+        "  {}",
+        tr!(
+            "setup.installed_version",
+            installed.as_deref().unwrap_or(tr!("common.unknown"))
+        )
+    );
+    println!("  {}", tr!("setup.latest_version", latest.version));
+
+    let newer = match installed {
+        Some(ref v) => is_newer(&latest.version, v),
+        None if !path.exists() => true,
+        None => {
+            println!("  {}", tr!("setup.version_unknown"));
+            println!();
+            return Ok(None);
+        }
+    };
+    if newer {
+        println!("  {}", tr!("setup.update_available"));
+    } else {
+        println!("  {}", tr!("setup.up_to_date"));
+    }
+    println!();
+
+    Ok(newer.then_some(latest))
+}
+
+/// 仅在有新版本时升级词库，`force` 为真时总是下载最新版本
+pub fn update(force: bool) -> Result<()> {
+    let release = if force {
+        Some(fetch_latest_release()?)
+    } else {
+        check_update()?
+    };
+    if let Some(release) = release {
+        download_db(&db_path()?, &release)?;
+    }
+    Ok(())
+}
+
+#[derive(Deserialize)]
+struct GithubRelease {
+    tag_name: String,
+    assets: Vec<GithubAsset>,
+}
+
+#[derive(Deserialize)]
+struct GithubAsset {
+    name: String,
+    browser_download_url: String,
+    /// 形如 "sha256:<hex>"
+    digest: Option<String>,
+}
+
+/// 从发布清单获取最新的 SQLite 词库
+fn fetch_latest_release() -> Result<DbRelease> {
+    fetch_release(RELEASE_MANIFEST_URL)
+}
+
+/// 获取发布清单中的 SQLite 词库及其摘要（GitHub 对较早上传的文件不提供摘要）
+fn fetch_release(manifest_url: &str) -> Result<DbRelease> {
+    let client = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(10))
+        .user_agent("lango-cli/0.1")
+        .build()?;
+
+    let resp = client
+        .get(manifest_url)
+        .send()
+        .context(tr!("setup.fetch_release_failed"))?;
+    if !resp.status().is_success() {
+        anyhow::bail!(tr!("setup.fetch_release_status", resp.status()));
+    }
+    let release: GithubRelease = resp.json().context(tr!("setup.bad_release"))?;
+
+    let asset = release
+        .assets
+        .into_iter()
+        .find(|a| a.name.starts_with("ecdict-sqlite") && a.name.ends_with(".zip"))
+        .with_context(|| tr!("setup.no_sqlite_asset", release.tag_name))?;
+
+    let sha256 = asset
+        .digest
+        .and_then(|d| d.strip_prefix("sha256:").map(str::to_string));
+
+    Ok(DbRelease {
+        version: release.tag_name.trim_start_matches('v').to_string(),
+        url: asset.browser_download_url,
+        sha256,
+    })
+}
+
+/// 比较点分版本号，`candidate` 比 `installed` 新时返回 true
+fn is_newer(candidate: &str, installed: &str) -> bool {
+    let parse = |v: &str| -> Vec<u64> {
+        v.trim_start_matches('v')
+            .split('.')
+            .map(|p| p.parse().unwrap_or(0))
+            .collect()
+    };
+    parse(candidate) > parse(installed)
+}
+
+/// 从本地文件导入词库，`name` 为空时导入为默认词库
+pub fn import_db(source: &PathBuf, name: Option<&str>) -> Result<PathBuf> {
+    let dest = match name {
+        Some(name) => dicts::dict_path(name)?,
+        None => db_path()?,
+    };
 
     if !source.exists() {
-        anyhow::bail!("文件不存在: {}", source.display());
+        anyhow::bail!(tr!("setup.file_not_found", source.display()));
     }
 
     // 创建目标目录
@@ -61,48 +244,155 @@ pub fn import_db(source: &PathBuf) -> Result<PathBuf> {
         fs::create_dir_all(parent)?;
     }
 
-    // 判断是 zip 还是 db 文件
-    let ext = source.extension().and_then(|e| e.to_str()).unwrap_or("");
-    if ext == "zip" {
-        extract_zip(source, &dest)?;
-    } else {
-        fs::copy(source, &dest)
-            .with_context(|| format!("复制文件失败: {} -> {}", source.display(), dest.display()))?;
-    }
+    // 判断是 zip、csv 还是 db 文件，验证通过后才替换现有词库
+    let ext = source
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+    install_from(&dest, |tmp| {
+        if ext == "zip" {
+            extract_zip(source, tmp)
+        } else if ext == "csv" {
+            let file = fs::File::open(source)
+                .with_context(|| tr!("common.open_file_failed", source.display()))?;
+            let size = file.metadata()?.len();
+            import_csv(file, size, tmp)
+        } else {
+            fs::copy(source, tmp)
+                .with_context(|| tr!("setup.copy_failed", source.display(), tmp.display()))?;
+            Ok(())
+        }
+    })?;
+
+    write_meta(
+        &dest,
+        &DbMeta {
+            version: None,
+            source: source.display().to_string(),
+            sha256: None,
+            installed_at: now_secs(),
+        },
+    )?;
+
+    println!("  {}", tr!("setup.imported", dest.display()));
+    Ok(dest)
+}
 
-    // 验证 SQLite 文件
-    validate_db(&dest)?;
+/// 词库发布版本
+#[derive(Debug, Clone)]
+pub struct DbRelease {
+    pub version: String,
+    pub url: String,
+    /// 压缩包的 SHA-256 摘要（十六进制），未知时不校验
+    pub sha256: Option<String>,
+}
 
-    println!("  词库已导入: {}", dest.display());
-    Ok(dest)
+/// 首次安装的默认词库版本
+fn default_release() -> DbRelease {
+    DbRelease {
+        version: DEFAULT_VERSION.to_string(),
+        url: DEFAULT_URL.to_string(),
+        sha256: DEFAULT_SHA256.map(str::to_string),
+    }
 }
 
 /// 下载词库
-fn download_db(dest: &PathBuf) -> Result<()> {
+///
+/// 下载到 `stardict.<版本>.zip.tmp`，中断后再次运行同一版本时通过 HTTP Range 续传；
+/// 解压到临时文件并校验通过后才原子替换现有词库。
+fn download_db(dest: &PathBuf, release: &DbRelease) -> Result<()> {
     // 创建目标目录
     if let Some(parent) = dest.parent() {
         fs::create_dir_all(parent)?;
     }
 
-    let url = "https://github.com/skywind3000/ECDICT/releases/download/1.0.28/ecdict-sqlite-28.zip";
-
     println!();
-    println!("  正在下载 ECDICT 词库...");
-    println!("  来源: {}", url);
+    println!("  {}", tr!("setup.downloading", release.version));
+    println!("  {}", tr!("setup.source", release.url));
     println!();
 
+    // 文件名包含版本号，避免把另一版本的部分下载续传到本版本上
+    let zip_path = dest.with_extension(format!("{}.zip.tmp", release.version));
+    download_resumable(&release.url, &zip_path)?;
+
+    // 下载完成后无论校验、解压成功与否都删除临时 zip，
+    // 否则下次运行会把它当作已完整的续传文件而重复失败
+    let installed = verify_and_install(dest, &zip_path, release);
+    let _ = fs::remove_file(&zip_path);
+    let actual = installed?;
+
+    write_meta(
+        dest,
+        &DbMeta {
+            version: Some(release.version.clone()),
+            source: release.url.clone(),
+            sha256: Some(actual),
+            installed_at: now_secs(),
+        },
+    )?;
+
+    println!("  {}", tr!("setup.installed", dest.display()));
+    println!();
+
+    Ok(())
+}
+
+/// 校验压缩包摘要并安装，返回实际摘要
+fn verify_and_install(dest: &PathBuf, zip_path: &PathBuf, release: &DbRelease) -> Result<String> {
+    println!("  {}", tr!("setup.verifying"));
+    let actual = sha256_file(zip_path)?;
+    match release.sha256 {
+        Some(ref expected) if !actual.eq_ignore_ascii_case(expected) => {
+            anyhow::bail!(tr!("setup.checksum_mismatch", expected, actual));
+        }
+        Some(_) => {}
+        None => println!("  {}", tr!("setup.no_digest", release.version).yellow()),
+    }
+    println!("  SHA-256: {}", actual);
+
+    println!("  {}", tr!("setup.extracting"));
+    install_from(dest, |tmp| extract_zip(zip_path, tmp))?;
+    Ok(actual)
+}
+
+/// 下载文件到 `path`，若文件已存在则从其末尾续传
+fn download_resumable(url: &str, path: &PathBuf) -> Result<()> {
     let client = reqwest::blocking::Client::builder()
         .timeout(std::time::Duration::from_secs(300))
         .user_agent("lango-cli/0.1")
         .build()?;
 
-    let resp = client.get(url).send().context("下载失败，请检查网络连接")?;
+    let existing = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
 
-    if !resp.status().is_success() {
-        anyhow::bail!("下载失败: HTTP {}", resp.status());
+    let mut request = client.get(url);
+    if existing > 0 {
+        request = request.header(reqwest::header::RANGE, format!("bytes={}-", existing));
     }
+    let resp = request.send().context(tr!("setup.download_failed"))?;
 
-    let total_size = resp.content_length().unwrap_or(0);
+    let status = resp.status();
+    if status == reqwest::StatusCode::RANGE_NOT_SATISFIABLE && existing > 0 {
+        // 临时文件已完整
+        return Ok(());
+    }
+    if !status.is_success() {
+        anyhow::bail!(tr!("setup.download_status", status));
+    }
+
+    // 服务器不支持 Range 时返回 200，需要从头下载
+    let resumed = status == reqwest::StatusCode::PARTIAL_CONTENT;
+    let mut file = if resumed {
+        println!(
+            "  {}",
+            tr!("setup.resuming", indicatif::HumanBytes(existing))
+        );
+        fs::OpenOptions::new().append(true).open(path)?
+    } else {
+        fs::File::create(path)?
+    };
+    let mut downloaded: u64 = if resumed { existing } else { 0 };
+    let total_size = downloaded + resp.content_length().unwrap_or(0);
 
     let pb = ProgressBar::new(total_size);
     pb.set_style(
@@ -111,11 +401,8 @@ fn download_db(dest: &PathBuf) -> Result<()> {
             .unwrap()
             .progress_chars("=>-"),
     );
+    pb.set_position(downloaded);
 
-    // 下载到临时 zip 文件
-    let zip_path = dest.with_extension("zip.tmp");
-    let mut file = fs::File::create(&zip_path)?;
-    let mut downloaded: u64 = 0;
     let mut reader = resp;
     let mut buffer = [0u8; 8192];
 
@@ -129,41 +416,233 @@ fn download_db(dest: &PathBuf) -> Result<()> {
         pb.set_position(downloaded);
     }
 
-    pb.finish_with_message("下载完成");
-    drop(file);
+    pb.finish_with_message(tr!("setup.download_done"));
+    Ok(())
+}
 
-    println!("  正在解压...");
-    extract_zip(&zip_path, dest)?;
+/// 计算文件的 SHA-256（十六进制小写）
+fn sha256_file(path: &PathBuf) -> Result<String> {
+    use sha2::{Digest, Sha256};
 
-    // 删除临时 zip
-    let _ = fs::remove_file(&zip_path);
+    let mut file =
+        fs::File::open(path).with_context(|| tr!("common.open_file_failed", path.display()))?;
+    let mut hasher = Sha256::new();
+    io::copy(&mut file, &mut hasher)?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
 
-    // 验证
-    validate_db(dest)?;
+/// 通过 `write` 生成临时词库，校验通过后原子替换 `dest`
+fn install_from(dest: &PathBuf, write: impl FnOnce(&PathBuf) -> Result<()>) -> Result<()> {
+    let tmp = dest.with_extension("db.tmp");
+    let result = write(&tmp).and_then(|_| validate_db(&tmp));
+    if let Err(e) = result {
+        let _ = fs::remove_file(&tmp);
+        return Err(e);
+    }
 
-    println!("  词库安装成功: {}", dest.display());
-    println!();
+    fs::rename(&tmp, dest)
+        .with_context(|| tr!("setup.replace_failed", tmp.display(), dest.display()))?;
 
+    // 拼写索引失败不影响查询，只是缺少读音相近的建议，可稍后补建
+    if let Err(e) = build_spell_index(dest) {
+        println!("  {}", tr!("setup.spell_index_failed", e).yellow());
+    }
     Ok(())
 }
 
-/// 从 zip 文件中提取 stardict.db
+/// 为词库建立拼写索引并显示进度（安装词库与 `lango doctor --fix` 使用）
+pub fn build_spell_index(db: &Path) -> Result<()> {
+    println!("  {}", tr!("setup.building_spell_index"));
+    let dict = EcdictDictionary::open(db)?;
+    let pb = ProgressBar::new(0);
+    pb.set_style(
+        ProgressStyle::default_bar()
+            .template("  [{bar:40.cyan/blue}] {pos}/{len}")
+            .unwrap()
+            .progress_chars("=>-"),
+    );
+    let result = dict.build_spell_index(&mut |done, total| {
+        pb.set_length(total as u64);
+        pb.set_position(done as u64);
+    });
+    pb.finish_and_clear();
+    result
+}
+
+/// 从 zip 文件中提取词库，优先使用 .db 文件，其次导入 .csv 文件
 fn extract_zip(zip_path: &PathBuf, dest: &PathBuf) -> Result<()> {
     let file = fs::File::open(zip_path)
-        .with_context(|| format!("无法打开文件: {}", zip_path.display()))?;
+        .with_context(|| tr!("common.open_file_failed", zip_path.display()))?;
     let mut archive = zip::ZipArchive::new(file)?;
 
-    for i in 0..archive.len() {
-        let mut entry = archive.by_index(i)?;
-        let name = entry.name().to_string();
-        if name.ends_with(".db") {
-            let mut out = fs::File::create(dest)?;
-            io::copy(&mut entry, &mut out)?;
-            return Ok(());
+    let names: Vec<String> = archive.file_names().map(str::to_string).collect();
+    if let Some(name) = names.iter().find(|n| n.ends_with(".db")) {
+        let mut entry = archive.by_name(name)?;
+        let mut out = fs::File::create(dest)?;
+        io::copy(&mut entry, &mut out)?;
+        return Ok(());
+    }
+    if let Some(name) = names.iter().find(|n| n.ends_with(".csv")) {
+        let entry = archive.by_name(name)?;
+        let size = entry.size();
+        return import_csv(entry, size, dest);
+    }
+
+    anyhow::bail!(tr!("setup.zip_no_db"));
+}
+
+/// ECDICT 的 stardict 表结构（与官方 stardict.py 一致）
+const STARDICT_SCHEMA: &str = r#"
+CREATE TABLE IF NOT EXISTS "stardict" (
+    "id" INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL UNIQUE,
+    "word" VARCHAR(64) COLLATE NOCASE NOT NULL UNIQUE,
+    "sw" VARCHAR(64) COLLATE NOCASE NOT NULL,
+    "phonetic" VARCHAR(64),
+    "definition" TEXT,
+    "translation" TEXT,
+    "pos" VARCHAR(16),
+    "collins" INTEGER DEFAULT(0),
+    "oxford" INTEGER DEFAULT(0),
+    "tag" VARCHAR(64),
+    "bnc" INTEGER DEFAULT(NULL),
+    "frq" INTEGER DEFAULT(NULL),
+    "exchange" TEXT,
+    "detail" TEXT,
+    "audio" TEXT
+);
+"#;
+
+/// 索引在数据写入后再创建，加快导入
+const STARDICT_INDEXES: &str = r#"
+CREATE UNIQUE INDEX IF NOT EXISTS "stardict_1" ON stardict (id);
+CREATE UNIQUE INDEX IF NOT EXISTS "stardict_2" ON stardict (word);
+CREATE INDEX IF NOT EXISTS "stardict_3" ON stardict (sw, word collate nocase);
+CREATE INDEX IF NOT EXISTS "sd_1" ON stardict (word collate nocase);
+"#;
+
+/// 将 ECDICT CSV（ecdict.csv / mini / ultimate）流式导入为新的 SQLite 词库
+fn import_csv<R: Read>(reader: R, size: u64, dest: &PathBuf) -> Result<()> {
+    let _ = fs::remove_file(dest);
+    let mut conn = rusqlite::Connection::open(dest)?;
+    conn.execute_batch("PRAGMA journal_mode=OFF; PRAGMA synchronous=OFF;")?;
+    conn.execute_batch(STARDICT_SCHEMA)?;
+
+    let pb = ProgressBar::new(size);
+    pb.set_style(
+        ProgressStyle::default_bar()
+            .template("  [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({eta})")
+            .unwrap()
+            .progress_chars("=>-"),
+    );
+
+    let mut csv_reader = csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(pb.wrap_read(reader));
+
+    // 按表头定位列，兼容列顺序不同或缺列的变体
+    let headers = csv_reader
+        .headers()
+        .context(tr!("setup.csv_no_header"))?
+        .clone();
+    let column = |name: &str| headers.iter().position(|h| h.trim() == name);
+    let word_col = column("word").context(tr!("setup.csv_no_word"))?;
+    let columns = [
+        "phonetic",
+        "definition",
+        "translation",
+        "pos",
+        "collins",
+        "oxford",
+        "tag",
+        "bnc",
+        "frq",
+        "exchange",
+        "detail",
+        "audio",
+    ]
+    .map(column);
+
+    let tx = conn.transaction()?;
+    let mut count: u64 = 0;
+    {
+        let mut stmt = tx.prepare(
+            "INSERT OR IGNORE INTO stardict \
+             (word, sw, phonetic, definition, translation, pos, collins, oxford, \
+              tag, bnc, frq, exchange, detail, audio) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
+        )?;
+
+        for record in csv_reader.records() {
+            let record = record.context(tr!("setup.csv_malformed"))?;
+            let word = record.get(word_col).unwrap_or("").trim();
+            if word.is_empty() {
+                continue;
+            }
+
+            let text = |i: usize| -> Option<String> {
+                let value = record.get(columns[i]?)?;
+                (!value.is_empty()).then(|| csv_unescape(value))
+            };
+            let int = |i: usize| -> Option<i64> { record.get(columns[i]?)?.trim().parse().ok() };
+
+            stmt.execute(rusqlite::params![
+                word,
+                strip_word(word),
+                text(0),
+                text(1),
+                text(2),
+                text(3),
+                int(4).unwrap_or(0),
+                int(5).unwrap_or(0),
+                text(6),
+                int(7),
+                int(8),
+                text(9),
+                text(10),
+                text(11),
+            ])?;
+            count += 1;
         }
     }
+    tx.commit()?;
+    pb.finish_and_clear();
+
+    println!("  {}", tr!("setup.building_indexes"));
+    conn.execute_batch(STARDICT_INDEXES)?;
+    println!("  {}", tr!("setup.csv_imported", count));
+
+    Ok(())
+}
+
+/// ECDICT 的 sw 列：仅保留字母数字并转小写
+fn strip_word(word: &str) -> String {
+    word.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
 
-    anyhow::bail!("zip 文件中未找到 .db 文件");
+/// 还原 ECDICT CSV 中转义的换行 (`\n`、`\r`、`\\`)
+fn csv_unescape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some('\\') => out.push('\\'),
+            Some(other) => {
+                out.push('\\');
+                out.push(other);
+            }
+            None => out.push('\\'),
+        }
+    }
+    out
 }
 
 /// 验证 SQLite 数据库文件
@@ -173,11 +652,79 @@ fn validate_db(path: &PathBuf) -> Result<()> {
         .query_row("SELECT COUNT(*) FROM stardict LIMIT 1", [], |row| {
             row.get(0)
         })
-        .context("词库文件损坏或格式不正确")?;
+        .context(tr!("setup.db_corrupt"))?;
 
     if count == 0 {
-        anyhow::bail!("词库为空");
+        anyhow::bail!(tr!("setup.db_empty"));
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_release_needs_no_manifest() {
+        let release = default_release();
+        assert_eq!(release.version, DEFAULT_VERSION);
+        assert!(release.url.contains(&format!("/{}/", DEFAULT_VERSION)));
+    }
+
+    #[test]
+    fn digest_mismatch_rejects_the_archive() {
+        let dir = std::env::temp_dir().join(format!("lango-verify-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let zip = dir.join("stardict.zip.tmp");
+        fs::write(&zip, b"not a zip").unwrap();
+        let release = DbRelease {
+            version: "0".to_string(),
+            url: String::new(),
+            sha256: Some("0".repeat(64)),
+        };
+
+        let dest = dir.join("stardict.db");
+        let err = verify_and_install(&dest, &zip, &release).unwrap_err();
+        assert!(!dest.exists());
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(err.to_string().contains(&"0".repeat(64)));
+    }
+
+    #[test]
+    fn newer_versions_compare_numerically() {
+        assert!(is_newer("1.0.29", "1.0.28"));
+        assert!(is_newer("1.0.100", "1.0.28"));
+        assert!(is_newer("2.0", "1.9.9"));
+        assert!(!is_newer("1.0.28", "1.0.28"));
+        assert!(!is_newer("1.0.27", "1.0.28"));
+    }
+
+    #[test]
+    fn version_prefix_and_length_are_tolerated() {
+        assert!(!is_newer("v1.0.28", "1.0.28"));
+        assert!(is_newer("v1.0.29", "1.0.28"));
+        assert!(is_newer("1.0.28.1", "1.0.28"));
+    }
+
+    #[test]
+    fn csv_unescape_restores_escaped_newlines() {
+        assert_eq!(csv_unescape(r"n. 苹果\nv. 吃"), "n. 苹果\nv. 吃");
+        assert_eq!(csv_unescape(r"a\r\nb"), "a\r\nb");
+        assert_eq!(csv_unescape(r"back\\slash"), "back\\slash");
+    }
+
+    #[test]
+    fn csv_unescape_keeps_unknown_escapes() {
+        assert_eq!(csv_unescape(r"a\tb"), "a\\tb");
+        assert_eq!(csv_unescape("trailing\\"), "trailing\\");
+        assert_eq!(csv_unescape("plain"), "plain");
+    }
+
+    #[test]
+    fn strip_word_keeps_lowercase_alphanumerics() {
+        assert_eq!(strip_word("Ice-Cream"), "icecream");
+        assert_eq!(strip_word("o'clock"), "oclock");
+        assert_eq!(strip_word("3D"), "3d");
+    }
+}
@@ -0,0 +1,193 @@
+use anyhow::{Context, Result};
+use std::collections::{HashMap, HashSet};
+use std::io::Read;
+use std::path::Path;
+
+use lango::dict::DictionaryService;
+use lango::tr;
+use lango::types::WordInfo;
+
+/// 读取文本文件，路径为 `-` 时读取标准输入
+pub fn read_input(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        let mut text = String::new();
+        std::io::stdin().read_to_string(&mut text)?;
+        return Ok(text);
+    }
+    std::fs::read_to_string(path).with_context(|| tr!("text.read_failed", path.display()))
+}
+
+/// 读取外部单词表（`gloss --known`、`known import`）：每行取行首的词（小写），忽略空行与 `#` 注释
+pub fn read_word_list(path: &Path) -> Result<HashSet<String>> {
+    let content =
+        std::fs::read_to_string(path).with_context(|| tr!("text.read_failed", path.display()))?;
+    Ok(content
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .filter_map(|l| l.split_whitespace().next())
+        .map(str::to_lowercase)
+        .collect())
+}
+
+/// 将英文文本切分为小写单词
+///
+/// 连字符与其他标点均视为分隔符；撇号只在字母之间时保留，随后去掉所有格
+/// 与缩写部分（`teacher's` → `teacher`，`don't` → `do`，`won't` → `will`）。
+pub fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c.is_ascii_alphabetic() {
+            current.push(c.to_ascii_lowercase());
+        } else if (c == '\'' || c == '’')
+            && !current.is_empty()
+            && chars.peek().is_some_and(|n| n.is_ascii_alphabetic())
+        {
+            current.push('\'');
+        } else if !current.is_empty() {
+            tokens.push(strip_clitic(std::mem::take(&mut current)));
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(strip_clitic(current));
+    }
+    tokens
+}
+
+/// 去掉所有格与缩写附着成分
+fn strip_clitic(token: String) -> String {
+    match token.as_str() {
+        "won't" => return "will".to_string(),
+        "can't" => return "can".to_string(),
+        "shan't" => return "shall".to_string(),
+        _ => {}
+    }
+    if let Some(base) = token.strip_suffix("n't") {
+        return base.to_string();
+    }
+    match token.split_once('\'') {
+        Some((base, _)) => base.to_string(),
+        None => token,
+    }
+}
+
+/// 文本中出现的一个词目（原形）
+pub struct Lemma {
+    pub info: WordInfo,
+    /// 各种词形的出现次数之和
+    pub count: usize,
+}
+
+/// 文本的词汇统计
+pub struct Vocabulary {
+    /// 单词总数
+    pub tokens: usize,
+    /// 词目，按首次出现的顺序排列
+    pub lemmas: Vec<Lemma>,
+    /// 词库中查不到的词（去重，按首次出现的顺序排列）
+    pub unknown: Vec<String>,
+}
+
+impl Vocabulary {
+    /// 对文本分词并还原词形，统计各词目的出现次数
+    pub fn collect(service: &DictionaryService, text: &str) -> Result<Self> {
+        let tokens = tokenize(text);
+        let mut lemmas: Vec<Lemma> = Vec::new();
+        let mut unknown = Vec::new();
+        // 词形 → 词目下标（None 表示词库未收录），避免重复查询
+        let mut forms: HashMap<String, Option<usize>> = HashMap::new();
+        // 词目原形 → 下标
+        let mut index: HashMap<String, usize> = HashMap::new();
+
+        for token in &tokens {
+            let slot = match forms.get(token) {
+                Some(&slot) => slot,
+                None => {
+                    let slot = match service.lemmatize(token)? {
+                        Some(info) => {
+                            let key = info.word.to_lowercase();
+                            Some(*index.entry(key).or_insert_with(|| {
+                                lemmas.push(Lemma { info, count: 0 });
+                                lemmas.len() - 1
+                            }))
+                        }
+                        None => {
+                            unknown.push(token.clone());
+                            None
+                        }
+                    };
+                    forms.insert(token.clone(), slot);
+                    slot
+                }
+            };
+            if let Some(i) = slot {
+                lemmas[i].count += 1;
+            }
+        }
+
+        Ok(Self {
+            tokens: tokens.len(),
+            lemmas,
+            unknown,
+        })
+    }
+}
+
+/// 释义压缩为一行，各词性之间以分号分隔
+pub fn brief_translation(info: &WordInfo) -> String {
+    info.translation
+        .as_deref()
+        .unwrap_or("")
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_word_list_takes_first_word_per_line() {
+        let path = std::env::temp_dir().join(format!("lango-words-{}.txt", std::process::id()));
+        std::fs::write(&path, "# 注释\nApple 苹果\n\n  banana\n#cherry\n").unwrap();
+        let words = read_word_list(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        let expected: HashSet<String> = ["apple", "banana"].map(String::from).into();
+        assert_eq!(words.unwrap(), expected);
+    }
+
+    #[test]
+    fn tokenize_lowercases_and_splits_on_punctuation() {
+        assert_eq!(
+            tokenize("The well-known Cat, sat!"),
+            ["the", "well", "known", "cat", "sat"]
+        );
+        assert!(tokenize("  123 — ...").is_empty());
+    }
+
+    #[test]
+    fn tokenize_strips_possessives_and_contractions() {
+        assert_eq!(
+            tokenize("The teacher's book isn't here; we won't go, I can't."),
+            [
+                "the", "teacher", "book", "is", "here", "we", "will", "go", "i", "can"
+            ]
+        );
+        assert_eq!(tokenize("don’t"), ["do"]);
+    }
+
+    #[test]
+    fn tokenize_drops_apostrophes_outside_words() {
+        assert_eq!(
+            tokenize("'quoted' students' rock'"),
+            ["quoted", "students", "rock"]
+        );
+    }
+}
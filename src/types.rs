@@ -1,57 +1,178 @@
+use serde::Serialize;
 use std::fmt;
 
+use crate::tr;
+
 /// 词典查询结果条目
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct DictionaryEntry {
     pub word: String,
     pub phonetic: Option<String>,
     pub translation: Option<String>,
     pub definition: Option<String>,
-    #[allow(dead_code)]
     pub pos: Option<String>,
     pub exchange: Option<String>,
-    #[allow(dead_code)]
     pub tag: Option<String>,
     pub examples: Vec<Example>,
     pub source: DataSource,
 }
 
 /// 例句
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Example {
     pub english: String,
     pub chinese: Option<String>,
 }
 
 /// 数据来源
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", content = "name", rename_all = "lowercase")]
+#[non_exhaustive]
 pub enum DataSource {
-    Local,
+    /// 本地词库，附带词库名称
+    Local(String),
     Online,
 }
 
 impl fmt::Display for DataSource {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            DataSource::Local => write!(f, "ECDICT (本地)"),
-            DataSource::Online => write!(f, "Free Dictionary API (在线)"),
+            DataSource::Local(name) if name == crate::dict::DEFAULT_DICT => {
+                write!(f, "{}", tr!("source.local", "ECDICT"))
+            }
+            DataSource::Local(name) => write!(f, "{}", tr!("source.local", name)),
+            DataSource::Online => write!(f, "{}", tr!("source.online")),
         }
     }
 }
 
+/// 搜索命中：词头 + 中文释义（用于词组、通配等列表输出）
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    pub word: String,
+    pub translation: Option<String>,
+}
+
+/// ECDICT 考试标签，按难度从低到高排列
+pub const EXAM_TAGS: &[&str] = &["zk", "gk", "cet4", "cet6", "ky", "toefl", "ielts", "gre"];
+
+/// 单词的词频、考试标签等元数据（用于难度筛选与统计）
+#[derive(Debug, Clone, Serialize)]
+pub struct WordInfo {
+    pub word: String,
+    pub phonetic: Option<String>,
+    pub translation: Option<String>,
+    /// 空格分隔的考试标签，如 `zk gk cet4`
+    pub tag: Option<String>,
+    pub exchange: Option<String>,
+    /// 柯林斯星级（0 表示无）
+    pub collins: u8,
+    /// 是否属于牛津 3000 核心词汇
+    pub oxford: bool,
+    /// 英国国家语料库词频排名
+    pub bnc: Option<i64>,
+    /// 当代语料库词频排名
+    pub frq: Option<i64>,
+}
+
+impl WordInfo {
+    /// 词频排名：取 frq 与 bnc 中较靠前者
+    pub fn rank(&self) -> Option<i64> {
+        match (self.frq, self.bnc) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    /// 考试标签
+    pub fn tags(&self) -> impl Iterator<Item = &str> {
+        self.tag.as_deref().unwrap_or("").split_whitespace()
+    }
+
+    /// 难度等级：所属考试中最容易者在 [`EXAM_TAGS`] 中的位置，无标签时为 `None`
+    pub fn level(&self) -> Option<usize> {
+        self.tags()
+            .filter_map(|t| EXAM_TAGS.iter().position(|e| *e == t))
+            .min()
+    }
+
+    /// 原形：exchange 字段中 `0:` 指向的词（本词为变形时）
+    pub fn lemma(&self) -> Option<&str> {
+        self.exchange
+            .as_deref()?
+            .split('/')
+            .find_map(|item| item.strip_prefix("0:"))
+            .filter(|lemma| !lemma.is_empty())
+    }
+}
+
+/// 单词池筛选条件（随机抽词、每日一词、测验等）
+///
+/// 各条件同时满足；均未指定时只保留带有考试标签、柯林斯星级或牛津标记的常见词。
+#[derive(Debug, Clone, Default)]
+pub struct WordFilter {
+    /// 考试标签，如 `cet6`
+    pub tag: Option<String>,
+    /// 只保留牛津 3000 核心词汇
+    pub oxford: bool,
+    /// 柯林斯星级下限（1-5）
+    pub min_collins: Option<u8>,
+    /// 词频排名上限
+    pub max_rank: Option<i64>,
+}
+
+/// 词头匹配模式
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum WordPattern {
+    /// 通配符：`?` 匹配单个字符，`*` 匹配任意个字符
+    Glob(String),
+    /// 正则表达式（不区分大小写）
+    Regex(String),
+}
+
 /// 查询结果
 #[derive(Debug)]
+#[non_exhaustive]
 pub enum LookupResult {
     Found(DictionaryEntry),
+    /// 未找到原词，自动纠正为最接近的单词
+    Corrected(DictionaryEntry),
     NotFound,
     Suggestions(Vec<String>),
 }
 
+/// 单个词典后端的查询结果（`--all` 对比模式）
+#[derive(Debug)]
+pub struct SourceResult {
+    pub source: DataSource,
+    pub entry: Option<DictionaryEntry>,
+    /// 查询出错时的错误信息（如在线词典被限流）
+    pub error: Option<String>,
+}
+
 /// 查询选项
+///
+/// 以后可能增加字段，请从 [`LookupOptions::default`] 创建后修改所需的字段。
 #[derive(Debug, Clone)]
+#[non_exhaustive]
 pub struct LookupOptions {
     pub show_english: bool,
     pub show_examples: bool,
     pub force_online: bool,
     pub max_examples: usize,
+    /// 未命中时自动显示最可信的拼写建议
+    pub auto_correct: bool,
+}
+
+impl Default for LookupOptions {
+    fn default() -> Self {
+        Self {
+            show_english: false,
+            show_examples: false,
+            force_online: false,
+            max_examples: 3,
+            auto_correct: false,
+        }
+    }
 }
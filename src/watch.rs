@@ -0,0 +1,257 @@
+use anyhow::Result;
+use colored::Colorize;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use lango::dict::DictionaryService;
+use lango::formatter;
+use lango::tr;
+use lango::types::LookupOptions;
+
+use crate::known::{self, KnownWords};
+
+/// 复制内容超过此单词数时不视为单词或短语
+const MAX_WORDS: usize = 4;
+/// 复制内容超过此字符数时直接忽略
+const MAX_CHARS: usize = 64;
+
+/// 剪贴板文本来源
+pub trait ClipboardSource {
+    /// 读取当前文本，剪贴板为空或不是文本时返回 `Ok(None)`
+    fn read_text(&mut self) -> Result<Option<String>>;
+
+    /// 来源是否已结束，结束后停止监视（系统剪贴板不会结束）
+    fn is_closed(&self) -> bool {
+        false
+    }
+}
+
+/// 系统剪贴板（Linux 下支持 X11 与 Wayland）
+pub struct SystemClipboard {
+    clipboard: arboard::Clipboard,
+    /// 读取主选区（鼠标选中即可，无需复制），仅 Linux 支持
+    primary: bool,
+}
+
+impl SystemClipboard {
+    pub fn new(primary: bool) -> Result<Self> {
+        if primary && !cfg!(target_os = "linux") {
+            anyhow::bail!(tr!("watch.primary_unsupported"));
+        }
+        let clipboard =
+            arboard::Clipboard::new().map_err(|e| anyhow::anyhow!(tr!("watch.open_failed", e)))?;
+        Ok(Self { clipboard, primary })
+    }
+}
+
+impl ClipboardSource for SystemClipboard {
+    fn read_text(&mut self) -> Result<Option<String>> {
+        let result = if self.primary {
+            read_primary(&mut self.clipboard)
+        } else {
+            self.clipboard.get_text()
+        };
+        match result {
+            Ok(text) => Ok(Some(text)),
+            // 剪贴板为空、内容为图片或暂时被占用时跳过本次读取
+            Err(arboard::Error::ContentNotAvailable)
+            | Err(arboard::Error::ConversionFailure)
+            | Err(arboard::Error::ClipboardOccupied) => Ok(None),
+            Err(e) => Err(anyhow::anyhow!(tr!("watch.read_failed", e))),
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn read_primary(clipboard: &mut arboard::Clipboard) -> Result<String, arboard::Error> {
+    use arboard::{GetExtLinux, LinuxClipboardKind};
+    clipboard
+        .get()
+        .clipboard(LinuxClipboardKind::Primary)
+        .text()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_primary(clipboard: &mut arboard::Clipboard) -> Result<String, arboard::Error> {
+    clipboard.get_text()
+}
+
+/// 监视剪贴板，复制新的单词或短语时输出词条，直到剪贴板来源结束或读取出错
+pub fn run(
+    clipboard: &mut impl ClipboardSource,
+    service: &DictionaryService,
+    options: &LookupOptions,
+    interval: Duration,
+) -> Result<()> {
+    let known = KnownWords::load()?;
+
+    println!();
+    println!("  {} {}", "◉".cyan(), tr!("watch.started"));
+    println!("  {}", tr!("watch.stop_hint").dimmed());
+    println!();
+
+    watch(clipboard, interval, |query| {
+        let start = Instant::now();
+        match service.lookup(query, options) {
+            Ok(result) => {
+                formatter::print_result(
+                    &result,
+                    query,
+                    options.show_english,
+                    options.show_examples,
+                    start.elapsed(),
+                );
+                known::annotate(service, &known, &result)?;
+            }
+            // 单次查询失败（如在线词典被限流）不中断监视
+            Err(e) => {
+                println!("  {} {}", "✗".red(), e.to_string().dimmed());
+                println!();
+            }
+        }
+        Ok(())
+    })
+}
+
+/// 每隔 `interval` 读取一次剪贴板，内容变化且像单词或短语时调用 `on_query`
+///
+/// 启动时已在剪贴板中的内容不会查询；内容不变（包括被清空后又恢复）时不重复查询。
+fn watch(
+    clipboard: &mut impl ClipboardSource,
+    interval: Duration,
+    mut on_query: impl FnMut(&str) -> Result<()>,
+) -> Result<()> {
+    let mut last = clipboard.read_text()?;
+    while !clipboard.is_closed() {
+        thread::sleep(interval);
+        let text = clipboard.read_text()?;
+        if text.is_none() || text == last {
+            continue;
+        }
+        last = text;
+
+        if let Some(query) = last.as_deref().and_then(normalize) {
+            on_query(&query)?;
+        }
+    }
+    Ok(())
+}
+
+/// 将复制的文本整理为查询词，不像单词或短语时返回 `None`
+///
+/// 去掉首尾标点与 PDF 换行连字符（`conti-\nnuous` → `continuous`），合并空白。
+fn normalize(text: &str) -> Option<String> {
+    if text.chars().count() > MAX_CHARS * 2 {
+        return None;
+    }
+    let joined = text.replace("-\r\n", "").replace("-\n", "");
+    let trimmed = joined.trim_matches(|c: char| !c.is_alphabetic());
+    let words: Vec<&str> = trimmed.split_whitespace().collect();
+    if words.is_empty() || words.len() > MAX_WORDS {
+        return None;
+    }
+    let query = words.join(" ").replace('’', "'");
+    let plausible = query.chars().count() <= MAX_CHARS
+        && query
+            .chars()
+            .all(|c| c.is_ascii_alphabetic() || matches!(c, ' ' | '-' | '\'' | '.'));
+    plausible.then_some(query)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    /// 依次返回预设内容的剪贴板，内容读完即结束
+    struct Script(VecDeque<Result<Option<&'static str>>>);
+
+    impl Script {
+        fn new(reads: &[Option<&'static str>]) -> Self {
+            Self(reads.iter().map(|r| Ok(*r)).collect())
+        }
+    }
+
+    impl ClipboardSource for Script {
+        fn read_text(&mut self) -> Result<Option<String>> {
+            match self.0.pop_front() {
+                Some(read) => read.map(|text| text.map(str::to_string)),
+                None => Ok(None),
+            }
+        }
+
+        fn is_closed(&self) -> bool {
+            self.0.is_empty()
+        }
+    }
+
+    fn queries(clipboard: &mut Script) -> Result<Vec<String>> {
+        let mut seen = Vec::new();
+        watch(clipboard, Duration::ZERO, |query| {
+            seen.push(query.to_string());
+            Ok(())
+        })?;
+        Ok(seen)
+    }
+
+    #[test]
+    fn watch_skips_initial_and_repeated_contents() {
+        let mut clipboard = Script::new(&[
+            Some("already there"),
+            Some("hello"),
+            Some("hello"),
+            None,
+            Some("hello"),
+            Some("world"),
+            Some("12345"),
+            Some("hello"),
+        ]);
+        assert_eq!(
+            queries(&mut clipboard).unwrap(),
+            ["hello", "world", "hello"]
+        );
+    }
+
+    #[test]
+    fn watch_stops_on_read_errors() {
+        let mut clipboard = Script::new(&[None, Some("hello")]);
+        clipboard.0.push_back(Err(anyhow::anyhow!("gone")));
+        clipboard.0.push_back(Ok(Some("world")));
+        assert!(queries(&mut clipboard).is_err());
+        assert_eq!(clipboard.0.len(), 1);
+    }
+
+    #[test]
+    fn normalize_joins_hyphenated_line_breaks() {
+        assert_eq!(normalize("conti-\nnuous").as_deref(), Some("continuous"));
+        assert_eq!(normalize("conti-\r\nnuous").as_deref(), Some("continuous"));
+        assert_eq!(normalize("well-known").as_deref(), Some("well-known"));
+    }
+
+    #[test]
+    fn normalize_trims_punctuation_and_whitespace() {
+        assert_eq!(normalize("“Hello,”").as_deref(), Some("Hello"));
+        assert_eq!(normalize("  (take \n off).  ").as_deref(), Some("take off"));
+        assert_eq!(normalize("don’t").as_deref(), Some("don't"));
+        assert_eq!(normalize("..."), None);
+    }
+
+    #[test]
+    fn normalize_rejects_long_or_non_word_text() {
+        assert_eq!(
+            normalize("one two three four").as_deref(),
+            Some("one two three four")
+        );
+        assert_eq!(normalize("one two three four five"), None);
+        assert_eq!(
+            normalize(&"a".repeat(MAX_CHARS)).map(|q| q.len()),
+            Some(MAX_CHARS)
+        );
+        assert_eq!(normalize(&"a".repeat(MAX_CHARS + 1)), None);
+        assert_eq!(normalize(&format!("a{}a", " ".repeat(MAX_CHARS * 2))), None);
+        assert_eq!(normalize("mp3 file"), None);
+        // 末尾的脚注编号等按标点去掉
+        assert_eq!(normalize("word12").as_deref(), Some("word"));
+        assert_eq!(normalize("你好"), None);
+    }
+}